@@ -0,0 +1,43 @@
+//! Batched transition function example.
+
+use seqgen::prelude::*;
+
+fn main() {
+    // a(n) = 1 if n is prime, 0 otherwise, each block is sieved at once.
+    let mut is_prime = Sequence::new().batched_transition_function(1024, |_, indices, buffer| {
+        let start = indices.start;
+        let mut block = vec![1_u8; indices.len()];
+
+        for n in indices.clone().take_while(|n| *n < 2) {
+            block[n - start] = 0;
+        }
+
+        let mut divisor = 2;
+        while divisor * divisor < indices.end {
+            let first_multiple = (start.div_ceil(divisor) * divisor).max(divisor * divisor);
+
+            for multiple in (first_multiple..indices.end).step_by(divisor) {
+                block[multiple - start] = 0;
+            }
+
+            divisor += 1;
+        }
+
+        buffer.extend(block);
+
+        Ok(())
+    });
+
+    println!(
+        "{:?}",
+        is_prime.range_mut(0, 20).unwrap().collect::<Vec<_>>()
+    );
+    println!("{}", is_prime.alive_elements_len());
+
+    let primes_below_10_000 = is_prime
+        .range_mut(0, 10_000)
+        .unwrap()
+        .filter(|flag| *flag == 1)
+        .count();
+    println!("{primes_below_10_000}");
+}
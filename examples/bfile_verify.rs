@@ -0,0 +1,26 @@
+//! Verification of a sequence against an OEIS b-file example.
+
+use seqgen::{oeis::BFile, prelude::*};
+
+fn main() {
+    // Beginning of the b-file of A000045 (Fibonacci numbers) with a typo in a(9).
+    let bfile: BFile<u128> = "# A000045\n0 0\n1 1\n2 1\n3 2\n4 3\n5 5\n6 8\n7 13\n8 21\n9 35\n"
+        .parse()
+        .unwrap();
+
+    let mut fib_seq = Sequence::new().recurrence([0, 1_u128], |[a, b], _| a + b);
+    let verification = bfile
+        .verify(&mut fib_seq, bfile.offset().unwrap_or(0))
+        .unwrap();
+
+    match verification.mismatch() {
+        Some(mismatch) => println!(
+            "a({}) mismatch: expected {}, got {} ({} entries matched)",
+            mismatch.n,
+            mismatch.expected,
+            mismatch.actual,
+            verification.matched()
+        ),
+        None => println!("All {} entries matched", verification.matched()),
+    }
+}
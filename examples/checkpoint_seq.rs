@@ -0,0 +1,38 @@
+//! Checkpoints of a recurrence example.
+
+use seqgen::prelude::*;
+
+const MODULUS: u64 = 1_000_000_007;
+
+fn fib_mod(window: [&u64; 2], _: usize) -> u64 {
+    (window[0] + window[1]) % MODULUS
+}
+
+fn main() {
+    // Only the window is kept in storage, the checkpoints allow recomputing any segment.
+    let mut fib_seq = Sequence::new()
+        .recurrence([0, 1_u64], fib_mod)
+        .bounded_storage()
+        .checkpoint_every(100_000);
+
+    fib_seq.generate(1_000_000);
+    println!("{} checkpoints", fib_seq.checkpoints().count());
+
+    let segment = fib_seq.recompute_segment(500_000, 500_005).unwrap();
+    println!("F(500000..500005) mod p = {segment:?}");
+
+    let report = fib_seq.verify_checkpoints().unwrap();
+    println!(
+        "{} segments verified, consistent: {}",
+        report.segments(),
+        report.is_consistent()
+    );
+
+    // The segments can be verified in parallel with the recurrence of the sequence.
+    let report = fib_seq.par_verify_checkpoints(fib_mod).unwrap();
+    println!(
+        "{} segments verified in parallel, consistent: {}",
+        report.segments(),
+        report.is_consistent()
+    );
+}
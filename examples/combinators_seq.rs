@@ -0,0 +1,22 @@
+//! Sequence combinators example.
+
+use seqgen::prelude::*;
+
+fn main() {
+    let squares = Sequence::linear_seq().map_seq(|n| n * n);
+    let evens = Sequence::new().transition_function(|_, n| 2 * n);
+
+    // Squares of the even numbers, b(n) = a(c(n)).
+    let mut even_squares = squares.compose(evens);
+    println!("{}", even_squares.nth_element(10));
+
+    let mut pairs = even_squares.enumerate_seq().zip_seq(Sequence::linear_seq());
+    pairs
+        .range_mut(2, 5)
+        .unwrap()
+        .for_each(|((index, element), n)| println!("{index} {element} {n}"));
+
+    let mut interleaved =
+        Sequence::linear_seq().interleave(Sequence::linear_seq().map_seq(|n| 100 + n));
+    println!("{:?}", interleaved.by_ref().take(6).collect::<Vec<_>>());
+}
@@ -0,0 +1,20 @@
+//! Comparison of two transition functions example.
+
+use seqgen::prelude::*;
+
+fn main() {
+    // Triangular numbers computed from the closed form and from partial sums.
+    let mut closed_form = Sequence::new().transition_function(|_, n| n * (n + 1) / 2);
+    let mut partial_sums = Sequence::new()
+        .initial_elements(vec![0])
+        .transition_function(|alive_elements, n| {
+            alive_elements.nth_element(n - 1).unwrap() + n + usize::from(n == 50)
+        });
+
+    let report = PrefixComparison::new(100)
+        .run(&mut closed_form, &mut partial_sums)
+        .unwrap();
+
+    println!("First difference: {:?}", report.first_difference());
+    println!("Agreement runs: {:?}", report.agreement_runs());
+}
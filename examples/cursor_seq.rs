@@ -0,0 +1,32 @@
+//! Independent cursors over one sequence example.
+
+use seqgen::prelude::*;
+
+fn main() {
+    let mut slow = Sequence::new()
+        .initial_elements(vec![0, 1_u64])
+        .transition_function(|alive_elements, n| {
+            alive_elements.nth_element(n - 1).unwrap() + alive_elements.nth_element(n - 2).unwrap()
+        })
+        .into_cursor();
+    let mut fast = slow.clone();
+
+    fast.advance_by(10);
+    println!(
+        "slow: {} fast: {}",
+        slow.peek().unwrap(),
+        fast.peek().unwrap()
+    );
+
+    println!("{:?}", slow.by_ref().take(5).collect::<Vec<_>>());
+    println!("{:?}", fast.by_ref().take(5).collect::<Vec<_>>());
+
+    slow.seek(50);
+    println!("{} at {}", slow.peek().unwrap(), slow.position());
+
+    fast.rewind();
+    println!("{} at {}", fast.peek().unwrap(), fast.position());
+
+    // The elements were generated once for both cursors.
+    println!("{}", fast.sequence().borrow().alive_elements_len());
+}
@@ -0,0 +1,16 @@
+//! Sequence defined by a recurrence expression example.
+
+use seqgen::expression::parse_sequence;
+
+fn main() {
+    let definition = "
+        # Jacobsthal numbers
+        a(n) = a(n-1) + 2*a(n-2)
+        a(0) = 0; a(1) = 1
+    ";
+
+    match parse_sequence::<i128>(definition) {
+        Ok(seq) => seq.take(20).for_each(|element| println!("{element}")),
+        Err(error) => println!("{error}"),
+    }
+}
@@ -0,0 +1,20 @@
+//! Fallible transition function example.
+
+use seqgen::prelude::*;
+
+fn main() {
+    // a(n) = a(a(n - 1)) + 1, the initial elements make it read ahead of the alive elements.
+    let mut seq = Sequence::new()
+        .initial_elements(vec![1, 3_usize])
+        .fallible_transition_function(|alive_elements, current_element_index| {
+            let previous = alive_elements.try_nth_element(current_element_index - 1)?;
+            let element = alive_elements.try_nth_element(*previous)?;
+
+            Ok(element + 1)
+        });
+
+    match seq.try_nth_element(10) {
+        Ok(element) => println!("{element}"),
+        Err(error) => println!("{error}"),
+    }
+}
@@ -0,0 +1,51 @@
+//! Parameterised sequence families example.
+
+use seqgen::prelude::*;
+
+fn main() {
+    for k in 2..6 {
+        let mut k_bonacci = Sequence::<u64, _, _>::k_bonacci(k);
+        println!(
+            "{k}: {:?}",
+            k_bonacci.range_mut(0, 12).unwrap().collect::<Vec<_>>()
+        );
+    }
+
+    let mut arithmetic = Sequence::arithmetic(3_i64, 4);
+    println!(
+        "{:?}",
+        arithmetic.range_mut(0, 5).unwrap().collect::<Vec<_>>()
+    );
+
+    // Changing the parameter generates the elements again.
+    arithmetic.set_parameter((0_i64, -1_i64)).unwrap();
+    println!(
+        "{:?}",
+        arithmetic.range_mut(0, 5).unwrap().collect::<Vec<_>>()
+    );
+
+    let mut geometric = Sequence::geometric(1.0, 0.5);
+    println!(
+        "{:?}",
+        geometric.range_mut(0, 5).unwrap().collect::<Vec<_>>()
+    );
+
+    // n^2 + n + 41
+    let mut euler = Sequence::polynomial(vec![41_u64, 1, 1]);
+    println!("{:?}", euler.range_mut(0, 5).unwrap().collect::<Vec<_>>());
+
+    // A custom family: affine maps a(n) = m * a(n - 1) + c.
+    let affine = |m: i64, c: i64| {
+        Sequence::new()
+            .initial_elements(vec![1])
+            .parameterized_transition_function((m, c), |alive_elements, n, (m, c): &(i64, i64)| {
+                Ok(m * alive_elements.try_nth_element(n - 1)? + c)
+            })
+    };
+    let mut doubling = affine(2, 1);
+    println!(
+        "{:?} {:?}",
+        doubling.range_mut(0, 5).unwrap().collect::<Vec<_>>(),
+        doubling.parameter::<(i64, i64)>()
+    );
+}
@@ -0,0 +1,12 @@
+//! Fibonacci sequence defined as a recurrence example.
+
+use seqgen::prelude::*;
+
+fn main() {
+    let mut fib_seq = Sequence::new()
+        .recurrence([0, 1_u128], |[a, b], _| a + b)
+        .bounded_storage(); // only the last two elements are kept
+
+    println!("{}", fib_seq.nth_element(185)); // more than 185 will cause u128 overflow
+    println!("Alive elements: {}", fib_seq.alive_elements_len());
+}
@@ -0,0 +1,30 @@
+//! Ordered merge of monotone sequences example.
+
+use seqgen::prelude::*;
+
+fn main() {
+    let multiples_of_3 = || Sequence::new().transition_function(|_, n| 3 * n);
+
+    // Multiples of 3 or 5.
+    let mut multiples = MonotoneMerge::new()
+        .source(multiples_of_3())
+        .source(Sequence::new().transition_function(|_, n| 5 * n))
+        .union();
+    println!("{:?}", multiples.by_ref().take(10).collect::<Vec<_>>());
+
+    // Odd numbers that are not multiples of 3.
+    let mut odd_non_multiples = MonotoneMerge::new()
+        .source(Sequence::new().transition_function(|_, n| 2 * n + 1))
+        .source(multiples_of_3())
+        .difference();
+    println!(
+        "{:?}",
+        odd_non_multiples.by_ref().take(10).collect::<Vec<_>>()
+    );
+
+    let hamming_numbers = Sequence::hamming_numbers().pre_generate(20);
+    hamming_numbers
+        .alive_elements()
+        .for_each(|element| print!("{element} "));
+    println!();
+}
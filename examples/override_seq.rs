@@ -0,0 +1,21 @@
+//! What-if analysis with overridden elements example.
+
+use seqgen::prelude::*;
+
+fn main() {
+    // Population that grows by 10% per year.
+    let mut population = Sequence::new()
+        .initial_elements(vec![1000.0])
+        .transition_function(|alive_elements, n| alive_elements.nth_element(n - 1).unwrap() * 1.1);
+    println!("{:.0}", population.nth_element(20));
+
+    // What if the population halved in year 10?
+    let halved = population.nth_element(10) / 2.0;
+    population.set_override(10, halved);
+    println!("{:.0}", population.nth_element(20));
+
+    // The override survives clear.
+    population.clear();
+    println!("{:.0}", population.nth_element(20));
+    println!("{:?}", population.overrides().collect::<Vec<_>>());
+}
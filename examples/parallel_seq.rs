@@ -0,0 +1,36 @@
+//! Parallel generation of an index-only sequence example.
+
+use seqgen::prelude::*;
+
+fn main() {
+    // The elements are functions of the index, so they are generated in parallel.
+    let mut seq = Sequence::new()
+        .index_function(|index| {
+            // Number of steps of the Collatz process that starts from index + 1.
+            let mut n = index as u64 + 1;
+            let mut steps = 0_u32;
+
+            while n != 1 {
+                n = if n.is_multiple_of(2) {
+                    n / 2
+                } else {
+                    3 * n + 1
+                };
+                steps += 1;
+            }
+
+            steps
+        })
+        .par_pre_generate(1_000_000);
+
+    let (index, steps) = seq
+        .alive_elements()
+        .enumerate()
+        .max_by_key(|(_, steps)| *steps)
+        .unwrap();
+
+    println!("{} takes {steps} steps", index + 1);
+
+    seq.par_generate(1_000_000);
+    println!("{} elements are alive", seq.alive_elements_len());
+}
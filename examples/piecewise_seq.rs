@@ -0,0 +1,34 @@
+//! Piecewise sequence and rule replacement example.
+
+use seqgen::{expression::RecurrenceDefinition, prelude::*};
+
+fn main() {
+    // a(n) = a(n - 1) + 1 before index 5, and a(n) = 2 * a(n - 1) from index 5.
+    let mut piecewise = Sequence::new()
+        .initial_elements(vec![0_u64])
+        .transition_function(|alive_elements, n| alive_elements.nth_element(n - 1).unwrap() + 1);
+    piecewise.replace_transition_function_from(
+        5,
+        |alive_elements, n| alive_elements.nth_element(n - 1).unwrap() * 2,
+        ReplacementMode::KeepAlive,
+    );
+    println!(
+        "{:?}",
+        piecewise.range_mut(0, 10).unwrap().collect::<Vec<_>>()
+    );
+
+    // Reload the rule from index 8, the alive elements from index 8 are generated again.
+    let rule: RecurrenceDefinition<u64> = "a(n) = a(n-1) + a(n-2); a(0) = 0; a(1) = 1"
+        .parse()
+        .unwrap();
+    rule.replace_from(&mut piecewise, 8, ReplacementMode::Invalidate)
+        .unwrap();
+    println!(
+        "{:?}",
+        piecewise.range_mut(0, 10).unwrap().collect::<Vec<_>>()
+    );
+    println!(
+        "{:?}",
+        piecewise.transition_function_segments().collect::<Vec<_>>()
+    );
+}
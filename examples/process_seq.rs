@@ -0,0 +1,36 @@
+//! Stochastic process sequences example.
+
+use seqgen::{
+    prelude::*,
+    random::{process::TransitionMatrix, Normal, RandomStream, UniformInt},
+};
+
+fn main() {
+    let stream = RandomStream::new(7);
+
+    // Weather: 0 is sunny, 1 is rainy, starting sunny.
+    let matrix = TransitionMatrix::new(&[vec![0.9, 0.1], vec![0.5, 0.5]]).unwrap();
+    let mut weather = Sequence::new()
+        .initial_elements(vec![0])
+        .markov_chain(matrix, stream.substream(0));
+    println!(
+        "{:?}",
+        weather.range_mut(0, 20).unwrap().collect::<Vec<_>>()
+    );
+
+    let mut walk = Sequence::new()
+        .initial_elements(vec![0_i64])
+        .random_walk(UniformInt::new(-1, 1).unwrap(), stream.substream(1));
+    println!("{:?}", walk.range_mut(0, 20).unwrap().collect::<Vec<_>>());
+
+    // AR(2) process, only the last two elements are kept.
+    let mut ar = Sequence::new()
+        .initial_elements(vec![0.0, 0.0])
+        .autoregressive(
+            vec![0.6, -0.2],
+            Normal::new(0.0, 1.0).unwrap(),
+            stream.substream(2),
+        )
+        .bounded_storage();
+    println!("{:.3}", ar.nth_element(1_000_000));
+}
@@ -0,0 +1,46 @@
+//! Progress reporting and throughput statistics example.
+
+use std::ops::Range;
+
+use seqgen::prelude::*;
+
+/// Prints the progress of the generation every 10%.
+#[derive(Default)]
+struct ProgressObserver {
+    indices: Range<usize>,
+    last_percent: usize,
+}
+
+impl<T> SequenceObserver<T> for ProgressObserver {
+    fn on_batch_start(&mut self, indices: Range<usize>) {
+        self.indices = indices;
+        self.last_percent = 0;
+    }
+
+    fn on_element(&mut self, index: usize, _element: &T) {
+        let done = index + 1 - self.indices.start;
+        let percent = done * 100 / self.indices.len();
+
+        if percent >= self.last_percent + 10 {
+            self.last_percent = percent;
+            println!("{percent}%");
+        }
+    }
+}
+
+fn main() {
+    let throughput = ThroughputObserver::new();
+    let mut seq = Sequence::linear_seq();
+
+    seq.add_observer(ProgressObserver::default());
+    seq.add_observer(throughput.clone());
+    seq.generate(10_000_000);
+
+    let stats = throughput.stats();
+    println!(
+        "Generated {} elements in {:?} ({:.0} elements/sec)",
+        stats.elements(),
+        stats.total_time(),
+        stats.elements_per_sec()
+    );
+}
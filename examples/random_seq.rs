@@ -0,0 +1,34 @@
+//! Seeded random sequences example.
+
+use seqgen::{
+    prelude::*,
+    random::{Discrete, Normal, RandomStream, UniformInt},
+};
+
+fn main() {
+    let stream = RandomStream::new(42);
+
+    // Any sample can be read directly, without the preceding ones.
+    let dice = UniformInt::new(1, 6).unwrap();
+    println!("{}", stream.sample(&dice, 1_000_000_000));
+
+    let mut rolls = stream.substream(1).sequence(dice);
+    println!("{:?}", rolls.range_mut(0, 10).unwrap().collect::<Vec<_>>());
+
+    let noise = stream
+        .substream(2)
+        .sequence(Normal::new(0.0, 1.0).unwrap())
+        .pre_generate(10_000);
+    let mean = noise.alive_elements().sum::<f64>() / noise.alive_elements_len() as f64;
+    println!("{mean:.3}");
+
+    // The far away samples of a sequence can be reached without storing the preceding ones.
+    let mut rolls = stream.substream(1).sequence(dice).bounded_storage();
+    println!("{}", rolls.nth_element(10_000_000));
+    println!("Stored samples: {}", rolls.alive_elements_len());
+
+    let mut weighted = stream
+        .substream(3)
+        .sequence(Discrete::new(&[0.7, 0.2, 0.1]).unwrap());
+    println!("{:?}", weighted.by_ref().take(10).collect::<Vec<_>>());
+}
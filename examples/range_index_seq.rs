@@ -0,0 +1,45 @@
+//! Range queries with indexes maintained as the sequence grows example.
+
+use seqgen::prelude::*;
+
+fn main() {
+    let mut seq = Sequence::new()
+        .initial_elements(vec![0, 1_i64])
+        .transition_function(|alive_elements, current_element_index| {
+            (alive_elements
+                .nth_element(current_element_index - 1)
+                .unwrap()
+                + alive_elements
+                    .nth_element(current_element_index - 2)
+                    .unwrap())
+                % 1_000
+        });
+
+    // Clones of the indexes are kept to query them.
+    let sums = PrefixSums::new();
+    let even = FenwickTree::new(|element: &i64| element % 2 == 0);
+    let extremes = SparseTable::new();
+
+    seq.add_range_index(sums.clone());
+    seq.add_range_index(even.clone());
+    seq.add_range_index(extremes.clone());
+
+    seq.generate(100_000);
+
+    println!("sum of 1000..2000: {:?}", sums.sum(1_000..2_000));
+    println!("prefix sums overflowed: {}", sums.overflowed());
+    println!(
+        "even elements in 1000..2000: {:?}",
+        even.count(1_000..2_000)
+    );
+    println!(
+        "min and max of 1000..1010: {:?} {:?}",
+        extremes.min(1_000..1_010),
+        extremes.max(1_000..1_010)
+    );
+
+    // The indexes follow the truncation of the sequence.
+    seq.truncate(1_500);
+    println!("indexed elements after truncation: {}", sums.len());
+    println!("sum of 1000..2000: {:?}", sums.sum(1_000..2_000));
+}
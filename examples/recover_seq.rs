@@ -0,0 +1,36 @@
+//! Recovery from a panicking transition function example.
+
+use std::panic;
+
+use seqgen::prelude::*;
+
+fn main() {
+    // The panic is reported by the error, the default hook would print it too.
+    panic::set_hook(Box::new(|_| {}));
+
+    let mut seq = Sequence::new()
+        .initial_elements(vec![0, 1_u8])
+        .transition_function(|alive_elements, current_index| {
+            alive_elements
+                .nth_element(current_index - 1)
+                .unwrap()
+                .checked_add(*alive_elements.nth_element(current_index - 2).unwrap())
+                .expect("The element does not fit in a u8.")
+        });
+
+    if let Err(error) = seq.generate_checked(20) {
+        println!("{error}");
+        println!("Alive elements: {}", seq.alive_elements_len());
+    }
+
+    // The elements before the failing one are kept, the generation resumes from it.
+    seq.replace_transition_function(|alive_elements, current_index| {
+        alive_elements
+            .nth_element(current_index - 1)
+            .unwrap()
+            .saturating_add(*alive_elements.nth_element(current_index - 2).unwrap())
+    });
+    seq.generate_checked(20).unwrap();
+
+    println!("{:?}", seq.alive_elements().collect::<Vec<_>>());
+}
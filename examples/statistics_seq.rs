@@ -0,0 +1,53 @@
+//! Streaming statistics over sequence parts example.
+
+use std::thread;
+
+use seqgen::prelude::*;
+
+type Statistics = (Summary, QuantileSketch, Histogram);
+
+fn statistics() -> Statistics {
+    (
+        Summary::new(),
+        QuantileSketch::new(0.01),
+        Histogram::new(0.0, 1_000.0, 10),
+    )
+}
+
+fn main() {
+    // Each thread computes the statistics of its chunk, the partial statistics are merged.
+    let chunks: Vec<_> = (0..4)
+        .map(|chunk| {
+            thread::spawn(move || {
+                let mut seq =
+                    Sequence::new().transition_function(|_, index| (index % 1_000) as u64);
+                let start = chunk * 250_000;
+
+                seq.range_mut(start, start + 250_000)
+                    .unwrap()
+                    .statistics(statistics())
+            })
+        })
+        .collect();
+
+    let mut total = statistics();
+    for chunk in chunks {
+        total.merge(&chunk.join().unwrap());
+    }
+
+    let (summary, sketch, histogram) = total;
+
+    println!("count: {}, sum: {}", summary.count(), summary.sum());
+    println!(
+        "mean: {:?}, std dev: {:?}",
+        summary.mean(),
+        summary.std_dev()
+    );
+    println!("min: {:?}, max: {:?}", summary.min(), summary.max());
+    println!(
+        "median: {:?}, p99: {:?}",
+        sketch.median(),
+        sketch.quantile(0.99)
+    );
+    println!("histogram: {:?}", histogram.counts());
+}
@@ -0,0 +1,48 @@
+//! Mutually recursive sequences example.
+
+use seqgen::prelude::*;
+
+const MALE: usize = 0;
+const FEMALE: usize = 1;
+
+fn main() {
+    // Hofstadter Female and Male sequences:
+    // F(n) = n - M(F(n - 1)) and M(n) = n - F(M(n - 1)).
+    // F(n) can read M(n), so M is generated first.
+    let mut hofstadter = SequenceSystem::new()
+        .member(vec![0], |system, n| {
+            let m = system.try_nth_element(MALE, n - 1)?;
+            Ok(n - system.try_nth_element(FEMALE, *m)?)
+        })
+        .member(vec![1], |system, n| {
+            let f = system.try_nth_element(FEMALE, n - 1)?;
+            Ok(n - system.try_nth_element(MALE, *f)?)
+        });
+
+    hofstadter.try_generate_nth_element(19).unwrap();
+    println!(
+        "F: {:?}",
+        hofstadter.alive_elements(FEMALE).collect::<Vec<_>>()
+    );
+    println!(
+        "M: {:?}",
+        hofstadter.range(MALE, 10, 20).unwrap().collect::<Vec<_>>()
+    );
+
+    // Discretised predator-prey model.
+    let mut predator_prey = SequenceSystem::new()
+        .member(vec![10.0], |system, n| {
+            let prey = system.try_nth_element(0, n - 1)?;
+            let predators = system.try_nth_element(1, n - 1)?;
+            Ok(prey + 0.1 * (1.1 * prey - 0.4 * prey * predators))
+        })
+        .member(vec![5.0], |system, n| {
+            let prey = system.try_nth_element(0, n - 1)?;
+            let predators = system.try_nth_element(1, n - 1)?;
+            Ok(predators + 0.1 * (0.1 * prey * predators - 0.4 * predators))
+        });
+
+    let prey = *predator_prey.nth_element(0, 100);
+    let predators = *predator_prey.nth_element(1, 100);
+    println!("{prey:.2} {predators:.2}");
+}
@@ -0,0 +1,22 @@
+//! Generation within a time budget example.
+
+use std::time::Duration;
+
+use seqgen::prelude::*;
+
+fn main() {
+    let mut seq = Sequence::linear_seq();
+    let budget = GenerationBudget::new().time_budget(Duration::from_millis(100));
+
+    let progress = seq
+        .generate_nth_element_with_budget(1_000_000_000, &budget)
+        .unwrap();
+
+    match progress.stop() {
+        Some(stop) => println!(
+            "Stopped ({stop:?}) after generating {} elements",
+            progress.generated()
+        ),
+        None => println!("Generated {} elements", progress.generated()),
+    }
+}
@@ -0,0 +1,22 @@
+//! Lazy transforms example.
+
+use seqgen::prelude::*;
+
+fn main() {
+    let cubes = || Sequence::new().transition_function(|_, n| (n * n * n) as i64);
+
+    // The third differences of the cubes are constant.
+    let mut third_differences = cubes().kth_differences(3);
+    let mut partial_sums = cubes().partial_sums();
+
+    println!(
+        "{:?}",
+        third_differences.by_ref().take(5).collect::<Vec<_>>()
+    );
+    println!("{:?}", partial_sums.by_ref().take(10).collect::<Vec<_>>());
+
+    partial_sums
+        .range(5, 10)
+        .unwrap()
+        .for_each(|element| println!("{element}"));
+}
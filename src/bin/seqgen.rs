@@ -0,0 +1,296 @@
+//! Command-line tool that generates sequences and exports them.
+
+use std::{
+    fmt::Display,
+    fs::File,
+    io::{self, BufWriter, Write},
+    path::PathBuf,
+    process,
+};
+
+use clap::{Parser, ValueEnum};
+
+use seqgen::{
+    oeis,
+    prelude::*,
+    sequence::states::{WithInitialElements, WithTransitionFunction, WithoutInitialElements},
+    sequence_part::types::AliveElementsPart,
+};
+
+/// Generate built-in sequences and export a range of their elements.
+#[derive(Parser)]
+#[command(name = "seqgen", version, about)]
+struct Cli {
+    /// Sequence to generate.
+    #[arg(value_enum)]
+    sequence: BuiltinSequence,
+
+    /// Index of the first element of the range (inclusive).
+    #[arg(long, default_value_t = 0)]
+    start: usize,
+
+    /// Index of the end of the range (exclusive).
+    #[arg(long, default_value_t = 10)]
+    end: usize,
+
+    /// Output format.
+    #[arg(short, long, value_enum, default_value_t = Format::Text)]
+    format: Format,
+
+    /// Index of the first element of the sequence in the b-file numbering.
+    #[arg(long, default_value_t = 0)]
+    offset: i64,
+
+    /// Output file (stdout if not specified).
+    #[arg(short, long)]
+    output: Option<PathBuf>,
+}
+
+/// Built-in sequences.
+#[derive(Clone, Copy, ValueEnum)]
+enum BuiltinSequence {
+    /// a(n) = n.
+    Linear,
+    /// Fibonacci numbers.
+    Fibonacci,
+    /// Lucas numbers.
+    Lucas,
+    /// Triangular numbers.
+    Triangular,
+    /// Squares.
+    Squares,
+    /// Cubes.
+    Cubes,
+    /// Powers of two.
+    PowersOfTwo,
+    /// Factorial numbers.
+    Factorial,
+    /// Catalan numbers.
+    Catalan,
+    /// Prime numbers.
+    Primes,
+}
+
+/// Output formats.
+#[derive(Clone, Copy, ValueEnum)]
+enum Format {
+    /// One element per line.
+    Text,
+    /// Index and element columns with a header.
+    Csv,
+    /// One JSON object per line.
+    Jsonl,
+    /// OEIS b-file (`n a(n)` lines).
+    Bfile,
+}
+
+impl Format {
+    /// Writes the header of the format (if any).
+    fn write_header(&self, writer: &mut impl Write) -> io::Result<()> {
+        match self {
+            Format::Csv => writeln!(writer, "index,value"),
+            Format::Text | Format::Jsonl | Format::Bfile => Ok(()),
+        }
+    }
+
+    /// Writes an element of the sequence.
+    fn write_element(
+        &self,
+        writer: &mut impl Write,
+        index: usize,
+        offset: i64,
+        element: &impl Display,
+    ) -> io::Result<()> {
+        match self {
+            Format::Text => writeln!(writer, "{element}"),
+            Format::Csv => writeln!(writer, "{index},{element}"),
+            Format::Jsonl => writeln!(writer, "{{\"index\":{index},\"value\":{element}}}"),
+            Format::Bfile => oeis::write_entry(writer, index as i64 + offset, element),
+        }
+    }
+}
+
+/// Sequence of unsigned integers that requires initial elements.
+type InitialElementsSeq =
+    Sequence<u128, WithInitialElements, WithTransitionFunction<u128, WithInitialElements>>;
+
+/// Sequence of unsigned integers that does not require initial elements.
+type IndexSeq =
+    Sequence<u128, WithoutInitialElements, WithTransitionFunction<u128, WithoutInitialElements>>;
+
+fn main() {
+    let cli = Cli::parse();
+
+    if let Err(error) = run(&cli) {
+        eprintln!("seqgen: {error}");
+        process::exit(1);
+    }
+}
+
+/// Generates the requested sequence and writes its range to the output.
+fn run(cli: &Cli) -> Result<(), Box<dyn std::error::Error>> {
+    if cli.start > cli.end {
+        return Err(RangeError::InvalidRange {
+            start: cli.start,
+            end: cli.end,
+        }
+        .into());
+    }
+
+    let writer: Box<dyn Write> = match &cli.output {
+        Some(path) => Box::new(File::create(path)?),
+        None => Box::new(io::stdout().lock()),
+    };
+    let mut writer = BufWriter::new(writer);
+
+    match cli.sequence {
+        BuiltinSequence::Linear => write_range(cli, &mut writer, Sequence::linear_seq()),
+        BuiltinSequence::Fibonacci => write_range(cli, &mut writer, fibonacci_like_seq(0, 1)),
+        BuiltinSequence::Lucas => write_range(cli, &mut writer, fibonacci_like_seq(2, 1)),
+        BuiltinSequence::Triangular => write_range(cli, &mut writer, triangular_seq()),
+        BuiltinSequence::Squares => write_range(cli, &mut writer, squares_seq()),
+        BuiltinSequence::Cubes => write_range(cli, &mut writer, cubes_seq()),
+        BuiltinSequence::PowersOfTwo => write_range(cli, &mut writer, powers_of_two_seq()),
+        BuiltinSequence::Factorial => write_range(cli, &mut writer, factorial_seq()),
+        BuiltinSequence::Catalan => write_range(cli, &mut writer, catalan_seq()),
+        BuiltinSequence::Primes => write_range(cli, &mut writer, primes_seq()),
+    }
+}
+
+/// Writes the elements of the range of the sequence, the elements generated
+/// before a generation error are written before the error is returned.
+fn write_range<T: Display, I>(
+    cli: &Cli,
+    writer: &mut impl Write,
+    mut sequence: Sequence<T, I, WithTransitionFunction<T, I>>,
+) -> Result<(), Box<dyn std::error::Error>> {
+    cli.format.write_header(writer)?;
+
+    for index in cli.start..cli.end {
+        match sequence.try_nth_element(index) {
+            Ok(element) => cli
+                .format
+                .write_element(writer, index, cli.offset, element)?,
+            Err(error) => {
+                writer.flush()?;
+                return Err(error.into());
+            }
+        }
+    }
+
+    writer.flush()?;
+
+    Ok(())
+}
+
+/// Returns the element, or an overflow error if there is no element.
+fn checked(index: usize, element: Option<u128>) -> Result<u128, GenerationError> {
+    element.ok_or(GenerationError::Failed {
+        index,
+        message: String::from("The element overflows u128."),
+    })
+}
+
+/// Returns a sequence where each element is the sum of the two preceding ones.
+fn fibonacci_like_seq(first: u128, second: u128) -> InitialElementsSeq {
+    Sequence::new()
+        .initial_elements(vec![first, second])
+        .fallible_transition_function(|alive_elements, current_element_index| {
+            let a = alive_elements.try_nth_element(current_element_index - 2)?;
+            let b = alive_elements.try_nth_element(current_element_index - 1)?;
+
+            checked(current_element_index, a.checked_add(*b))
+        })
+}
+
+fn triangular_seq() -> IndexSeq {
+    Sequence::new().fallible_transition_function(|_, current_element_index| {
+        let n = current_element_index as u128;
+
+        checked(current_element_index, n.checked_mul(n + 1).map(|n| n / 2))
+    })
+}
+
+fn squares_seq() -> IndexSeq {
+    Sequence::new().fallible_transition_function(|_, current_element_index| {
+        checked(
+            current_element_index,
+            (current_element_index as u128).checked_pow(2),
+        )
+    })
+}
+
+fn cubes_seq() -> IndexSeq {
+    Sequence::new().fallible_transition_function(|_, current_element_index| {
+        checked(
+            current_element_index,
+            (current_element_index as u128).checked_pow(3),
+        )
+    })
+}
+
+fn powers_of_two_seq() -> IndexSeq {
+    Sequence::new().fallible_transition_function(|_, current_element_index| {
+        let exponent = u32::try_from(current_element_index).ok();
+
+        checked(
+            current_element_index,
+            exponent.and_then(|exponent| 2_u128.checked_pow(exponent)),
+        )
+    })
+}
+
+fn factorial_seq() -> InitialElementsSeq {
+    Sequence::new()
+        .initial_elements(vec![1])
+        .fallible_transition_function(|alive_elements, current_element_index| {
+            let previous = alive_elements.try_nth_element(current_element_index - 1)?;
+
+            checked(
+                current_element_index,
+                previous.checked_mul(current_element_index as u128),
+            )
+        })
+}
+
+fn catalan_seq() -> InitialElementsSeq {
+    Sequence::new()
+        .initial_elements(vec![1])
+        .fallible_transition_function(|alive_elements, current_element_index| {
+            // C(n) = C(n - 1) * 2 * (2n - 1) / (n + 1)
+            let previous = alive_elements.try_nth_element(current_element_index - 1)?;
+            let n = current_element_index as u128;
+
+            checked(
+                current_element_index,
+                previous
+                    .checked_mul(2 * (2 * n - 1))
+                    .map(|product| product / (n + 1)),
+            )
+        })
+}
+
+fn primes_seq() -> InitialElementsSeq {
+    Sequence::new()
+        .initial_elements(vec![2])
+        .transition_function(|alive_elements, current_element_index| {
+            let mut candidate = alive_elements
+                .nth_element(current_element_index - 1)
+                .unwrap()
+                + 1;
+
+            while !is_prime(candidate, &alive_elements) {
+                candidate += 1;
+            }
+
+            candidate
+        })
+}
+
+/// Checks if the candidate is prime using the alive primes that precede it.
+fn is_prime(candidate: u128, primes: &AliveElementsPart<'_, u128, WithInitialElements>) -> bool {
+    (0..primes.len())
+        .map_while(|index| primes.nth_element(index))
+        .take_while(|prime| *prime * *prime <= candidate)
+        .all(|prime| !candidate.is_multiple_of(*prime))
+}
@@ -0,0 +1,123 @@
+//! This module defines the expressions of the expression language and their evaluation
+
+use std::fmt::Display;
+
+use crate::{
+    number::Number,
+    sequence::{error::GenerationError, states::WithInitialElements, types::GenerationResult},
+    sequence_part::types::AliveElementsPart,
+};
+
+/// A binary operator.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(super) enum Operator {
+    Add,
+    Subtract,
+    Multiply,
+    Divide,
+    Remainder,
+    Power,
+}
+
+impl Operator {
+    /// Applies the operator, returns None if the result is not representable.
+    fn apply<V: Number>(&self, left: &V, right: &V) -> Option<V> {
+        match self {
+            Operator::Add => left.checked_add(right),
+            Operator::Subtract => left.checked_sub(right),
+            Operator::Multiply => left.checked_mul(right),
+            Operator::Divide => left.checked_div(right),
+            Operator::Remainder => left.checked_rem(right),
+            Operator::Power => left.checked_pow(right),
+        }
+    }
+}
+
+impl Display for Operator {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let symbol = match self {
+            Operator::Add => "+",
+            Operator::Subtract => "-",
+            Operator::Multiply => "*",
+            Operator::Divide => "/",
+            Operator::Remainder => "%",
+            Operator::Power => "^",
+        };
+
+        write!(f, "{symbol}")
+    }
+}
+
+/// An expression of the expression language.
+#[derive(Debug, Clone, PartialEq)]
+pub(super) enum Expression<V> {
+    /// A numeric literal.
+    Number(V),
+    /// The index of the element being generated (`n`).
+    Index,
+    /// A reference to a preceding element (`a(n - k)`).
+    BackReference(usize),
+    /// Negation of an expression.
+    Negation(Box<Expression<V>>),
+    /// A binary operation.
+    Binary(Operator, Box<Expression<V>>, Box<Expression<V>>),
+}
+
+impl<V: Number> Expression<V> {
+    /// Returns the deepest back-reference of the expression (0 if there is none).
+    pub(super) fn depth(&self) -> usize {
+        match self {
+            Expression::Number(_) | Expression::Index => 0,
+            Expression::BackReference(distance) => *distance,
+            Expression::Negation(operand) => operand.depth(),
+            Expression::Binary(_, left, right) => left.depth().max(right.depth()),
+        }
+    }
+
+    /// Evaluates the expression for the element being generated.
+    pub(super) fn evaluate(
+        &self,
+        alive_elements: &AliveElementsPart<'_, V, WithInitialElements>,
+        current_element_index: usize,
+    ) -> GenerationResult<V> {
+        let failed = |message: String| GenerationError::Failed {
+            index: current_element_index,
+            message,
+        };
+
+        match self {
+            Expression::Number(value) => Ok(value.clone()),
+            Expression::Index => V::from_integer(current_element_index as i128)
+                .ok_or_else(|| failed(String::from("The index is not representable."))),
+            Expression::BackReference(distance) => alive_elements
+                .try_nth_element(current_element_index - distance)
+                .cloned(),
+            Expression::Negation(operand) => operand
+                .evaluate(alive_elements, current_element_index)?
+                .checked_neg()
+                .ok_or_else(|| failed(String::from("The negation is not representable."))),
+            Expression::Binary(operator, left, right) => {
+                let left = left.evaluate(alive_elements, current_element_index)?;
+                let right = right.evaluate(alive_elements, current_element_index)?;
+
+                operator.apply(&left, &right).ok_or_else(|| {
+                    failed(format!(
+                        "The result of `{left} {operator} {right}` is not representable."
+                    ))
+                })
+            }
+        }
+    }
+
+    /// Evaluates an expression that does not refer to the index or to the sequence.
+    pub(super) fn evaluate_constant(&self) -> Option<V> {
+        match self {
+            Expression::Number(value) => Some(value.clone()),
+            Expression::Index | Expression::BackReference(_) => None,
+            Expression::Negation(operand) => operand.evaluate_constant()?.checked_neg(),
+            Expression::Binary(operator, left, right) => {
+                operator.apply(&left.evaluate_constant()?, &right.evaluate_constant()?)
+            }
+        }
+    }
+}
@@ -0,0 +1,105 @@
+//! This module defines errors for the expression language
+
+use std::{error::Error, fmt::Display};
+
+/// Parse error happens when the text of a recurrence is not valid,
+/// it holds the position (line and column, starting from 1) of the error.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseError {
+    line: usize,
+    column: usize,
+    kind: ParseErrorKind,
+}
+
+/// The kind of a parse error.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ParseErrorKind {
+    /// A character that is not part of the language.
+    UnexpectedCharacter(char),
+    /// A token that is not expected at its position.
+    UnexpectedToken {
+        /// Description of the found token.
+        found: String,
+        /// Description of the expected token.
+        expected: &'static str,
+    },
+    /// A numeric literal that is not representable by the number type.
+    InvalidNumber(String),
+    /// A reference to the sequence that is not of the form `a(n - k)` with `k >= 1`.
+    InvalidBackReference,
+    /// An identifier that is neither the sequence nor its index.
+    UnknownIdentifier(String),
+    /// An element or the recurrence is defined more than once.
+    DuplicateDefinition,
+    /// The text does not define the recurrence (`a(n) = ...`).
+    MissingRecurrence,
+    /// An initial element required by the recurrence is not defined.
+    MissingInitialElement(usize),
+    /// An initial element that can not be evaluated (e.g. it refers to `n`).
+    InvalidInitialElement(usize),
+}
+
+impl ParseError {
+    /// Create new instance.
+    pub(super) fn new(line: usize, column: usize, kind: ParseErrorKind) -> Self {
+        Self { line, column, kind }
+    }
+
+    /// Returns the line of the error.
+    pub fn line(&self) -> usize {
+        self.line
+    }
+
+    /// Returns the column of the error.
+    pub fn column(&self) -> usize {
+        self.column
+    }
+
+    /// Returns the kind of the error.
+    pub fn kind(&self) -> &ParseErrorKind {
+        &self.kind
+    }
+}
+
+impl Display for ParseError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "Line {}, column {}: {}",
+            self.line, self.column, self.kind
+        )
+    }
+}
+
+impl Display for ParseErrorKind {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ParseErrorKind::UnexpectedCharacter(character) => {
+                write!(f, "Unexpected character `{character}`.")
+            }
+            ParseErrorKind::UnexpectedToken { found, expected } => {
+                write!(f, "Expected {expected}, found {found}.")
+            }
+            ParseErrorKind::InvalidNumber(literal) => write!(f, "Invalid number `{literal}`."),
+            ParseErrorKind::InvalidBackReference => write!(
+                f,
+                "References to the sequence must have the form `a(n - k)` with k >= 1."
+            ),
+            ParseErrorKind::UnknownIdentifier(identifier) => {
+                write!(f, "Unknown identifier `{identifier}`.")
+            }
+            ParseErrorKind::DuplicateDefinition => write!(f, "Duplicate definition."),
+            ParseErrorKind::MissingRecurrence => {
+                write!(f, "The recurrence (`a(n) = ...`) is not defined.")
+            }
+            ParseErrorKind::MissingInitialElement(index) => {
+                write!(f, "Initial element {index} is not defined.")
+            }
+            ParseErrorKind::InvalidInitialElement(index) => {
+                write!(f, "Initial element {index} can not be evaluated.")
+            }
+        }
+    }
+}
+
+impl Error for ParseError {}
@@ -0,0 +1,150 @@
+//! This module defines the lexer of the expression language
+
+use super::error::{ParseError, ParseErrorKind};
+
+/// The kind of a token.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(super) enum TokenKind {
+    Number(String),
+    Identifier(String),
+    Plus,
+    Minus,
+    Star,
+    Slash,
+    Percent,
+    Caret,
+    LeftParen,
+    RightParen,
+    Equals,
+    /// A statement separator (`;` or a new line).
+    Separator,
+    End,
+}
+
+impl TokenKind {
+    /// Returns the description of the token used in errors.
+    pub(super) fn describe(&self) -> String {
+        match self {
+            TokenKind::Number(literal) => format!("number `{literal}`"),
+            TokenKind::Identifier(identifier) => format!("identifier `{identifier}`"),
+            TokenKind::Plus => String::from("`+`"),
+            TokenKind::Minus => String::from("`-`"),
+            TokenKind::Star => String::from("`*`"),
+            TokenKind::Slash => String::from("`/`"),
+            TokenKind::Percent => String::from("`%`"),
+            TokenKind::Caret => String::from("`^`"),
+            TokenKind::LeftParen => String::from("`(`"),
+            TokenKind::RightParen => String::from("`)`"),
+            TokenKind::Equals => String::from("`=`"),
+            TokenKind::Separator => String::from("end of statement"),
+            TokenKind::End => String::from("end of input"),
+        }
+    }
+}
+
+/// A token and its position.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(super) struct Token {
+    pub(super) kind: TokenKind,
+    pub(super) line: usize,
+    pub(super) column: usize,
+}
+
+/// Splits the text into tokens, comments start with `#` and end at the end of the line.
+pub(super) fn tokenize(text: &str) -> Result<Vec<Token>, ParseError> {
+    let mut tokens = Vec::new();
+    let mut characters = text.chars().peekable();
+    let (mut line, mut column) = (1, 1);
+
+    while let Some(&character) = characters.peek() {
+        let (token_line, token_column) = (line, column);
+        let kind = match character {
+            '\n' | ';' => Some(TokenKind::Separator),
+            '#' => {
+                while characters.next_if(|character| *character != '\n').is_some() {
+                    column += 1;
+                }
+
+                continue;
+            }
+            '0'..='9' | '.' => {
+                let mut literal = String::new();
+
+                while let Some(digit) =
+                    characters.next_if(|character| character.is_ascii_digit() || *character == '.')
+                {
+                    literal.push(digit);
+                    column += 1;
+                }
+
+                tokens.push(Token {
+                    kind: TokenKind::Number(literal),
+                    line: token_line,
+                    column: token_column,
+                });
+
+                continue;
+            }
+            character if character.is_alphabetic() || character == '_' => {
+                let mut identifier = String::new();
+
+                while let Some(character) =
+                    characters.next_if(|character| character.is_alphanumeric() || *character == '_')
+                {
+                    identifier.push(character);
+                    column += 1;
+                }
+
+                tokens.push(Token {
+                    kind: TokenKind::Identifier(identifier),
+                    line: token_line,
+                    column: token_column,
+                });
+
+                continue;
+            }
+            character if character.is_whitespace() => None,
+            '+' => Some(TokenKind::Plus),
+            '-' => Some(TokenKind::Minus),
+            '*' => Some(TokenKind::Star),
+            '/' => Some(TokenKind::Slash),
+            '%' => Some(TokenKind::Percent),
+            '^' => Some(TokenKind::Caret),
+            '(' => Some(TokenKind::LeftParen),
+            ')' => Some(TokenKind::RightParen),
+            '=' => Some(TokenKind::Equals),
+            character => {
+                return Err(ParseError::new(
+                    line,
+                    column,
+                    ParseErrorKind::UnexpectedCharacter(character),
+                ))
+            }
+        };
+
+        characters.next();
+
+        if character == '\n' {
+            line += 1;
+            column = 1;
+        } else {
+            column += 1;
+        }
+
+        if let Some(kind) = kind {
+            tokens.push(Token {
+                kind,
+                line: token_line,
+                column: token_column,
+            });
+        }
+    }
+
+    tokens.push(Token {
+        kind: TokenKind::End,
+        line,
+        column,
+    });
+
+    Ok(tokens)
+}
@@ -0,0 +1,143 @@
+//! This module defines a small expression language that describes
+//! recurrences in text (e.g. in configuration files), and turns them into sequences.
+//!
+//! A recurrence is defined by statements separated by `;` or new lines,
+//! the recurrence itself (`a(n) = ...`) and its initial elements (`a(0) = ...`):
+//!
+//! ```text
+//! a(n) = a(n-1) + 2*a(n-2); a(0) = 1; a(1) = 1
+//! ```
+//!
+//! Expressions support numbers, the index `n`, references to preceding elements
+//! (`a(n - k)`), parentheses and the `+ - * / % ^` operators. Comments start with `#`.
+//! The number of required initial elements is inferred from the deepest reference.
+
+pub mod error;
+
+mod ast;
+mod lexer;
+mod parser;
+
+use std::str::FromStr;
+
+use self::{ast::Expression, error::ParseError, parser::Parser};
+
+use crate::{
+    number::Number,
+    sequence::{
+        states::{ReplacementMode, WithInitialElements, WithTransitionFunction},
+        types::GenerationResult,
+        Sequence,
+    },
+};
+
+/// A recurrence parsed from its text definition.
+#[derive(Debug, Clone, PartialEq)]
+pub struct RecurrenceDefinition<V> {
+    name: String,
+    initial_elements: Vec<V>,
+    order: usize,
+    expression: Expression<V>,
+}
+
+impl<V> RecurrenceDefinition<V> {
+    /// Create new instance.
+    fn new(
+        name: String,
+        initial_elements: Vec<V>,
+        order: usize,
+        expression: Expression<V>,
+    ) -> Self {
+        Self {
+            name,
+            initial_elements,
+            order,
+            expression,
+        }
+    }
+
+    /// Returns the name of the sequence (e.g. `a`).
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// Returns the initial elements.
+    pub fn initial_elements(&self) -> &[V] {
+        &self.initial_elements
+    }
+
+    /// Returns the order of the recurrence (its deepest reference to a preceding element).
+    pub fn order(&self) -> usize {
+        self.order
+    }
+}
+
+impl<V: Number + Send + 'static> RecurrenceDefinition<V> {
+    /// Turns the recurrence into a sequence, errors of the evaluation
+    /// (e.g. overflows or divisions by zero) are reported as generation errors.
+    pub fn into_sequence(
+        self,
+    ) -> Sequence<V, WithInitialElements, WithTransitionFunction<V, WithInitialElements>> {
+        let expression = self.expression;
+
+        Sequence::new()
+            .initial_elements(self.initial_elements)
+            .boxed_transition_function(
+                Box::new(move |alive_elements, current_element_index| {
+                    expression.evaluate(&alive_elements, current_element_index)
+                }),
+                Some(self.order),
+            )
+    }
+
+    /// Makes the recurrence generate the elements of the sequence from the index onward
+    /// (e.g. to reload a rule from configuration), the initial elements of the definition
+    /// are not used. The recurrence applies from its order at the earliest.
+    /// The look-back of the sequence grows to the order of the recurrence, with `KeepAlive`
+    /// the replacement is rejected if the elements it reads were dropped from storage
+    /// (with `Invalidate` they are generated again).
+    pub fn replace_from(
+        self,
+        sequence: &mut Sequence<
+            V,
+            WithInitialElements,
+            WithTransitionFunction<V, WithInitialElements>,
+        >,
+        index: usize,
+        mode: ReplacementMode,
+    ) -> GenerationResult<()> {
+        let expression = self.expression;
+
+        sequence.replace_boxed_transition_function_from(
+            index.max(self.order),
+            Box::new(move |alive_elements, current_element_index| {
+                expression.evaluate(&alive_elements, current_element_index)
+            }),
+            self.order,
+            mode,
+        )
+    }
+}
+
+impl<V: Number> FromStr for RecurrenceDefinition<V> {
+    type Err = ParseError;
+
+    fn from_str(text: &str) -> Result<Self, Self::Err> {
+        parse(text)
+    }
+}
+
+/// Parses the text definition of a recurrence.
+pub fn parse<V: Number>(text: &str) -> Result<RecurrenceDefinition<V>, ParseError> {
+    Parser::new(lexer::tokenize(text)?).parse()
+}
+
+/// Parses the text definition of a recurrence and turns it into a sequence.
+pub fn parse_sequence<V: Number + Send + 'static>(
+    text: &str,
+) -> Result<
+    Sequence<V, WithInitialElements, WithTransitionFunction<V, WithInitialElements>>,
+    ParseError,
+> {
+    parse(text).map(RecurrenceDefinition::into_sequence)
+}
@@ -0,0 +1,316 @@
+//! This module defines the parser of the expression language
+
+use std::collections::BTreeMap;
+
+use super::{
+    ast::{Expression, Operator},
+    error::{ParseError, ParseErrorKind},
+    lexer::{Token, TokenKind},
+    RecurrenceDefinition,
+};
+
+use crate::number::Number;
+
+/// The name of the index variable.
+const INDEX_VARIABLE: &str = "n";
+
+/// A recursive descent parser over the tokens of a recurrence.
+pub(super) struct Parser<V> {
+    tokens: Vec<Token>,
+    position: usize,
+    name: Option<String>,
+    initial_elements: BTreeMap<usize, V>,
+    recurrence: Option<(Expression<V>, Token)>,
+}
+
+impl<V: Number> Parser<V> {
+    /// Create new instance.
+    pub(super) fn new(tokens: Vec<Token>) -> Self {
+        Self {
+            tokens,
+            position: 0,
+            name: None,
+            initial_elements: BTreeMap::new(),
+            recurrence: None,
+        }
+    }
+
+    /// Parses all the statements and returns the recurrence they define.
+    pub(super) fn parse(mut self) -> Result<RecurrenceDefinition<V>, ParseError> {
+        loop {
+            while self.peek().kind == TokenKind::Separator {
+                self.advance();
+            }
+
+            if self.peek().kind == TokenKind::End {
+                break;
+            }
+
+            self.parse_statement()?;
+
+            let token = self.advance();
+            if !matches!(token.kind, TokenKind::Separator | TokenKind::End) {
+                return Err(unexpected(&token, "`;` or a new line"));
+            }
+        }
+
+        let end = self.peek().clone();
+        let (expression, head) = self.recurrence.ok_or_else(|| {
+            ParseError::new(end.line, end.column, ParseErrorKind::MissingRecurrence)
+        })?;
+
+        let order = expression.depth();
+        let initial_elements_len = self
+            .initial_elements
+            .last_key_value()
+            .map_or(order, |(index, _)| order.max(index + 1));
+
+        let initial_elements = (0..initial_elements_len)
+            .map(|index| {
+                self.initial_elements.remove(&index).ok_or_else(|| {
+                    ParseError::new(
+                        head.line,
+                        head.column,
+                        ParseErrorKind::MissingInitialElement(index),
+                    )
+                })
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+
+        Ok(RecurrenceDefinition::new(
+            self.name.unwrap_or_default(),
+            initial_elements,
+            order,
+            expression,
+        ))
+    }
+
+    /// Parses `a(n) = expression` or `a(k) = expression`.
+    fn parse_statement(&mut self) -> Result<(), ParseError> {
+        let head = self.advance();
+
+        match (&head.kind, &self.name) {
+            (TokenKind::Identifier(identifier), None) => self.name = Some(identifier.clone()),
+            (TokenKind::Identifier(identifier), Some(name)) if identifier == name => {}
+            (TokenKind::Identifier(identifier), Some(_)) => {
+                return Err(ParseError::new(
+                    head.line,
+                    head.column,
+                    ParseErrorKind::UnknownIdentifier(identifier.clone()),
+                ))
+            }
+            _ => return Err(unexpected(&head, "the name of the sequence")),
+        }
+
+        self.expect(TokenKind::LeftParen, "`(`")?;
+        let argument = self.advance();
+        self.expect(TokenKind::RightParen, "`)`")?;
+        self.expect(TokenKind::Equals, "`=`")?;
+
+        let expression = self.parse_expression()?;
+        let duplicate =
+            ParseError::new(head.line, head.column, ParseErrorKind::DuplicateDefinition);
+
+        match argument.kind {
+            TokenKind::Identifier(identifier) if identifier == INDEX_VARIABLE => {
+                if self.recurrence.is_some() {
+                    return Err(duplicate);
+                }
+
+                self.recurrence = Some((expression, head));
+            }
+            TokenKind::Number(literal) => {
+                let index = literal.parse().map_err(|_| {
+                    ParseError::new(
+                        argument.line,
+                        argument.column,
+                        ParseErrorKind::InvalidNumber(literal),
+                    )
+                })?;
+
+                let element = expression.evaluate_constant().ok_or_else(|| {
+                    ParseError::new(
+                        head.line,
+                        head.column,
+                        ParseErrorKind::InvalidInitialElement(index),
+                    )
+                })?;
+
+                if self.initial_elements.insert(index, element).is_some() {
+                    return Err(duplicate);
+                }
+            }
+            _ => {
+                return Err(unexpected(
+                    &argument,
+                    "`n` or the index of an initial element",
+                ))
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Parses `term (('+' | '-') term)*`.
+    fn parse_expression(&mut self) -> Result<Expression<V>, ParseError> {
+        let mut expression = self.parse_term()?;
+
+        loop {
+            let operator = match self.peek().kind {
+                TokenKind::Plus => Operator::Add,
+                TokenKind::Minus => Operator::Subtract,
+                _ => return Ok(expression),
+            };
+
+            self.advance();
+            expression =
+                Expression::Binary(operator, Box::new(expression), Box::new(self.parse_term()?));
+        }
+    }
+
+    /// Parses `unary (('*' | '/' | '%') unary)*`.
+    fn parse_term(&mut self) -> Result<Expression<V>, ParseError> {
+        let mut expression = self.parse_unary()?;
+
+        loop {
+            let operator = match self.peek().kind {
+                TokenKind::Star => Operator::Multiply,
+                TokenKind::Slash => Operator::Divide,
+                TokenKind::Percent => Operator::Remainder,
+                _ => return Ok(expression),
+            };
+
+            self.advance();
+            expression = Expression::Binary(
+                operator,
+                Box::new(expression),
+                Box::new(self.parse_unary()?),
+            );
+        }
+    }
+
+    /// Parses `'-' unary | power`.
+    fn parse_unary(&mut self) -> Result<Expression<V>, ParseError> {
+        if self.peek().kind == TokenKind::Minus {
+            self.advance();
+            return Ok(Expression::Negation(Box::new(self.parse_unary()?)));
+        }
+
+        self.parse_power()
+    }
+
+    /// Parses `primary ('^' unary)?`, the power is right associative.
+    fn parse_power(&mut self) -> Result<Expression<V>, ParseError> {
+        let base = self.parse_primary()?;
+
+        if self.peek().kind != TokenKind::Caret {
+            return Ok(base);
+        }
+
+        self.advance();
+
+        Ok(Expression::Binary(
+            Operator::Power,
+            Box::new(base),
+            Box::new(self.parse_unary()?),
+        ))
+    }
+
+    /// Parses a number, `n`, `a(n - k)` or a parenthesized expression.
+    fn parse_primary(&mut self) -> Result<Expression<V>, ParseError> {
+        let token = self.advance();
+
+        match &token.kind {
+            TokenKind::Number(literal) => V::from_literal(literal)
+                .map(Expression::Number)
+                .ok_or_else(|| {
+                    ParseError::new(
+                        token.line,
+                        token.column,
+                        ParseErrorKind::InvalidNumber(literal.clone()),
+                    )
+                }),
+            TokenKind::Identifier(identifier) if identifier == INDEX_VARIABLE => {
+                Ok(Expression::Index)
+            }
+            TokenKind::Identifier(identifier) if Some(identifier) == self.name.as_ref() => {
+                self.parse_back_reference(&token)
+            }
+            TokenKind::Identifier(identifier) => Err(ParseError::new(
+                token.line,
+                token.column,
+                ParseErrorKind::UnknownIdentifier(identifier.clone()),
+            )),
+            TokenKind::LeftParen => {
+                let expression = self.parse_expression()?;
+                self.expect(TokenKind::RightParen, "`)`")?;
+
+                Ok(expression)
+            }
+            _ => Err(unexpected(&token, "an expression")),
+        }
+    }
+
+    /// Parses `(n - k)` after the name of the sequence.
+    fn parse_back_reference(&mut self, name: &Token) -> Result<Expression<V>, ParseError> {
+        let invalid =
+            || ParseError::new(name.line, name.column, ParseErrorKind::InvalidBackReference);
+
+        self.expect(TokenKind::LeftParen, "`(`")?;
+
+        if self.advance().kind != TokenKind::Identifier(String::from(INDEX_VARIABLE))
+            || self.advance().kind != TokenKind::Minus
+        {
+            return Err(invalid());
+        }
+
+        let distance = match self.advance().kind {
+            TokenKind::Number(literal) => literal.parse().ok().filter(|distance| *distance >= 1),
+            _ => None,
+        }
+        .ok_or_else(invalid)?;
+
+        self.expect(TokenKind::RightParen, "`)`")?;
+
+        Ok(Expression::BackReference(distance))
+    }
+
+    /// Returns the current token.
+    fn peek(&self) -> &Token {
+        &self.tokens[self.position]
+    }
+
+    /// Returns the current token and moves to the next one (the end token is never passed).
+    fn advance(&mut self) -> Token {
+        let token = self.tokens[self.position].clone();
+
+        if token.kind != TokenKind::End {
+            self.position += 1;
+        }
+
+        token
+    }
+
+    /// Consumes the current token if it is of the expected kind.
+    fn expect(&mut self, kind: TokenKind, expected: &'static str) -> Result<(), ParseError> {
+        let token = self.advance();
+
+        if token.kind != kind {
+            return Err(unexpected(&token, expected));
+        }
+
+        Ok(())
+    }
+}
+
+/// Returns an error for an unexpected token.
+fn unexpected(token: &Token, expected: &'static str) -> ParseError {
+    ParseError::new(
+        token.line,
+        token.column,
+        ParseErrorKind::UnexpectedToken {
+            found: token.kind.describe(),
+            expected,
+        },
+    )
+}
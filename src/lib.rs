@@ -15,6 +15,10 @@
     html_favicon_url = "https://raw.githubusercontent.com/crazyrat13/seqgen/main/assets/seqgen_logo.svg"
 )]
 
+pub mod expression;
+pub mod number;
+pub mod oeis;
 pub mod prelude;
+pub mod random;
 pub mod sequence;
 pub mod sequence_part;
@@ -0,0 +1,359 @@
+//! This module defines the Number trait that abstracts over
+//! the numeric types the library computes with (integers, rationals and floats),
+//! and the Rational type.
+
+use std::{
+    cmp::Ordering,
+    fmt::{Debug, Display},
+    str::FromStr,
+};
+
+/// A numeric type whose operations report failures (overflow, division by zero, etc...)
+/// by returning None instead of panicking.
+pub trait Number: Clone + PartialEq + PartialOrd + Display + Debug {
+    /// Returns the additive identity.
+    fn zero() -> Self;
+
+    /// Returns the multiplicative identity.
+    fn one() -> Self;
+
+    /// Converts an integer to the number, returns None if it is not representable.
+    fn from_integer(value: i128) -> Option<Self>;
+
+    /// Parses a numeric literal (e.g. `12` or `1.5`), returns None if it is not representable.
+    fn from_literal(literal: &str) -> Option<Self>;
+
+    /// Checked addition.
+    fn checked_add(&self, other: &Self) -> Option<Self>;
+
+    /// Checked subtraction.
+    fn checked_sub(&self, other: &Self) -> Option<Self>;
+
+    /// Checked multiplication.
+    fn checked_mul(&self, other: &Self) -> Option<Self>;
+
+    /// Checked division.
+    fn checked_div(&self, other: &Self) -> Option<Self>;
+
+    /// Checked remainder.
+    fn checked_rem(&self, other: &Self) -> Option<Self>;
+
+    /// Checked exponentiation.
+    fn checked_pow(&self, exponent: &Self) -> Option<Self>;
+
+    /// Checked negation.
+    fn checked_neg(&self) -> Option<Self> {
+        Self::zero().checked_sub(self)
+    }
+
+    /// Converts the number to a float (possibly losing precision).
+    fn to_f64(&self) -> f64;
+}
+
+macro_rules! impl_number_for_integer {
+    ($($integer:ty),*) => {
+        $(
+            impl Number for $integer {
+                fn zero() -> Self {
+                    0
+                }
+
+                fn one() -> Self {
+                    1
+                }
+
+                fn from_integer(value: i128) -> Option<Self> {
+                    value.try_into().ok()
+                }
+
+                fn from_literal(literal: &str) -> Option<Self> {
+                    literal.parse().ok()
+                }
+
+                fn checked_add(&self, other: &Self) -> Option<Self> {
+                    <$integer>::checked_add(*self, *other)
+                }
+
+                fn checked_sub(&self, other: &Self) -> Option<Self> {
+                    <$integer>::checked_sub(*self, *other)
+                }
+
+                fn checked_mul(&self, other: &Self) -> Option<Self> {
+                    <$integer>::checked_mul(*self, *other)
+                }
+
+                fn checked_div(&self, other: &Self) -> Option<Self> {
+                    <$integer>::checked_div(*self, *other)
+                }
+
+                fn checked_rem(&self, other: &Self) -> Option<Self> {
+                    <$integer>::checked_rem(*self, *other)
+                }
+
+                fn checked_pow(&self, exponent: &Self) -> Option<Self> {
+                    <$integer>::checked_pow(*self, (*exponent).try_into().ok()?)
+                }
+
+                fn to_f64(&self) -> f64 {
+                    *self as f64
+                }
+            }
+        )*
+    };
+}
+
+impl_number_for_integer!(i32, i64, i128, u32, u64, u128, usize);
+
+impl Number for f64 {
+    fn zero() -> Self {
+        0.0
+    }
+
+    fn one() -> Self {
+        1.0
+    }
+
+    fn from_integer(value: i128) -> Option<Self> {
+        Some(value as f64)
+    }
+
+    fn from_literal(literal: &str) -> Option<Self> {
+        literal.parse().ok()
+    }
+
+    fn checked_add(&self, other: &Self) -> Option<Self> {
+        Some(self + other)
+    }
+
+    fn checked_sub(&self, other: &Self) -> Option<Self> {
+        Some(self - other)
+    }
+
+    fn checked_mul(&self, other: &Self) -> Option<Self> {
+        Some(self * other)
+    }
+
+    fn checked_div(&self, other: &Self) -> Option<Self> {
+        Some(self / other)
+    }
+
+    fn checked_rem(&self, other: &Self) -> Option<Self> {
+        Some(self % other)
+    }
+
+    fn checked_pow(&self, exponent: &Self) -> Option<Self> {
+        Some(self.powf(*exponent))
+    }
+
+    fn to_f64(&self) -> f64 {
+        *self
+    }
+}
+
+/// A rational number, it is always stored in its reduced form
+/// with a positive denominator.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Rational {
+    numerator: i128,
+    denominator: i128,
+}
+
+impl Rational {
+    /// Creates a new rational number, returns None if the denominator is zero.
+    pub fn new(numerator: i128, denominator: i128) -> Option<Self> {
+        if denominator == 0 {
+            return None;
+        }
+
+        let divisor = gcd(numerator, denominator);
+        let (numerator, denominator) = (numerator / divisor, denominator / divisor);
+
+        if denominator < 0 {
+            Some(Self {
+                numerator: numerator.checked_neg()?,
+                denominator: denominator.checked_neg()?,
+            })
+        } else {
+            Some(Self {
+                numerator,
+                denominator,
+            })
+        }
+    }
+
+    /// Returns the numerator.
+    pub fn numerator(&self) -> i128 {
+        self.numerator
+    }
+
+    /// Returns the denominator.
+    pub fn denominator(&self) -> i128 {
+        self.denominator
+    }
+
+    /// Checks if the rational number is an integer.
+    pub fn is_integer(&self) -> bool {
+        self.denominator == 1
+    }
+
+    /// Returns the reciprocal, returns None if the number is zero.
+    pub fn reciprocal(&self) -> Option<Self> {
+        Self::new(self.denominator, self.numerator)
+    }
+}
+
+/// Returns the greatest common divisor (1 if both numbers are zero).
+fn gcd(a: i128, b: i128) -> i128 {
+    let (mut a, mut b) = (a.unsigned_abs(), b.unsigned_abs());
+
+    while b != 0 {
+        (a, b) = (b, a % b);
+    }
+
+    if a == 0 {
+        1
+    } else {
+        a as i128
+    }
+}
+
+impl PartialOrd for Rational {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for Rational {
+    fn cmp(&self, other: &Self) -> Ordering {
+        let left = self.numerator.checked_mul(other.denominator);
+        let right = other.numerator.checked_mul(self.denominator);
+
+        match (left, right) {
+            (Some(left), Some(right)) => left.cmp(&right),
+            _ => self.to_f64().total_cmp(&other.to_f64()),
+        }
+    }
+}
+
+impl Display for Rational {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        if self.is_integer() {
+            write!(f, "{}", self.numerator)
+        } else {
+            write!(f, "{}/{}", self.numerator, self.denominator)
+        }
+    }
+}
+
+impl FromStr for Rational {
+    type Err = ();
+
+    /// Parses `p`, `p/q` or a decimal literal (e.g. `-1.25`).
+    fn from_str(text: &str) -> Result<Self, Self::Err> {
+        let text = text.trim();
+
+        if let Some((numerator, denominator)) = text.split_once('/') {
+            let numerator = numerator.trim().parse().map_err(|_| ())?;
+            let denominator = denominator.trim().parse().map_err(|_| ())?;
+            return Self::new(numerator, denominator).ok_or(());
+        }
+
+        Self::from_literal(text).ok_or(())
+    }
+}
+
+impl Number for Rational {
+    fn zero() -> Self {
+        Self {
+            numerator: 0,
+            denominator: 1,
+        }
+    }
+
+    fn one() -> Self {
+        Self {
+            numerator: 1,
+            denominator: 1,
+        }
+    }
+
+    fn from_integer(value: i128) -> Option<Self> {
+        Self::new(value, 1)
+    }
+
+    fn from_literal(literal: &str) -> Option<Self> {
+        let (integer_part, fraction_part) = literal.split_once('.').unwrap_or((literal, ""));
+
+        if fraction_part.starts_with(['+', '-']) {
+            return None;
+        }
+
+        let digits = format!("{integer_part}{fraction_part}");
+        let denominator = 10_i128.checked_pow(fraction_part.len().try_into().ok()?)?;
+
+        Self::new(digits.parse().ok()?, denominator)
+    }
+
+    fn checked_add(&self, other: &Self) -> Option<Self> {
+        let numerator = self
+            .numerator
+            .checked_mul(other.denominator)?
+            .checked_add(other.numerator.checked_mul(self.denominator)?)?;
+
+        Self::new(numerator, self.denominator.checked_mul(other.denominator)?)
+    }
+
+    fn checked_sub(&self, other: &Self) -> Option<Self> {
+        self.checked_add(&other.checked_neg()?)
+    }
+
+    fn checked_mul(&self, other: &Self) -> Option<Self> {
+        let left = Self::new(self.numerator, other.denominator)?;
+        let right = Self::new(other.numerator, self.denominator)?;
+
+        Self::new(
+            left.numerator.checked_mul(right.numerator)?,
+            left.denominator.checked_mul(right.denominator)?,
+        )
+    }
+
+    fn checked_div(&self, other: &Self) -> Option<Self> {
+        self.checked_mul(&other.reciprocal()?)
+    }
+
+    fn checked_rem(&self, other: &Self) -> Option<Self> {
+        let quotient = self.checked_div(other)?;
+        let truncated = Self::new(quotient.numerator / quotient.denominator, 1)?;
+
+        self.checked_sub(&truncated.checked_mul(other)?)
+    }
+
+    fn checked_pow(&self, exponent: &Self) -> Option<Self> {
+        if !exponent.is_integer() {
+            return None;
+        }
+
+        let power = u32::try_from(exponent.numerator.unsigned_abs()).ok()?;
+        let result = Self::new(
+            self.numerator.checked_pow(power)?,
+            self.denominator.checked_pow(power)?,
+        )?;
+
+        if exponent.numerator < 0 {
+            result.reciprocal()
+        } else {
+            Some(result)
+        }
+    }
+
+    fn checked_neg(&self) -> Option<Self> {
+        Some(Self {
+            numerator: self.numerator.checked_neg()?,
+            denominator: self.denominator,
+        })
+    }
+
+    fn to_f64(&self) -> f64 {
+        self.numerator as f64 / self.denominator as f64
+    }
+}
@@ -0,0 +1,78 @@
+//! This module defines errors for OEIS b-files
+
+use std::{error::Error, fmt::Display, io};
+
+use crate::sequence::error::GenerationError;
+
+/// B-file error happens when reading, writing or verifying b-files.
+#[derive(Debug)]
+pub enum BFileError {
+    /// Reading or writing failed.
+    Io(io::Error),
+    /// A line that is not of the form `n a(n)`.
+    InvalidLine {
+        /// Number of the line (starting from 1).
+        line: usize,
+    },
+    /// A line whose index can not be parsed.
+    InvalidIndex {
+        /// Number of the line (starting from 1).
+        line: usize,
+    },
+    /// A line whose value can not be parsed.
+    InvalidValue {
+        /// Number of the line (starting from 1).
+        line: usize,
+    },
+    /// An index of the b-file that comes before the first element of the sequence.
+    IndexBeforeOffset {
+        /// Index in the b-file.
+        n: i64,
+        /// Offset of the sequence.
+        offset: i64,
+    },
+    /// An element of the sequence could not be generated.
+    Generation(GenerationError),
+}
+
+impl Display for BFileError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            BFileError::Io(error) => write!(f, "{error}"),
+            BFileError::InvalidLine { line } => {
+                write!(f, "Line {line} is not of the form `n a(n)`.")
+            }
+            BFileError::InvalidIndex { line } => write!(f, "Line {line} has an invalid index."),
+            BFileError::InvalidValue { line } => write!(f, "Line {line} has an invalid value."),
+            BFileError::IndexBeforeOffset { n, offset } => {
+                write!(
+                    f,
+                    "Index {n} comes before the offset of the sequence ({offset})."
+                )
+            }
+            BFileError::Generation(error) => write!(f, "{error}"),
+        }
+    }
+}
+
+impl Error for BFileError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            BFileError::Io(error) => Some(error),
+            BFileError::Generation(error) => Some(error),
+            _ => None,
+        }
+    }
+}
+
+impl From<io::Error> for BFileError {
+    fn from(error: io::Error) -> Self {
+        BFileError::Io(error)
+    }
+}
+
+impl From<GenerationError> for BFileError {
+    fn from(error: GenerationError) -> Self {
+        BFileError::Generation(error)
+    }
+}
@@ -0,0 +1,251 @@
+//! This module defines reading, writing and verification of OEIS b-files.
+//!
+//! A b-file lists the elements of a sequence as `n a(n)` lines,
+//! lines starting with `#` are comments. The index `n` of the first element
+//! of the sequence is the offset of the sequence (usually 0 or 1).
+
+pub mod error;
+
+use std::{
+    fmt::Display,
+    io::{BufRead, Write},
+    str::FromStr,
+};
+
+use self::error::BFileError;
+
+use crate::sequence::{states::WithTransitionFunction, Sequence};
+
+/// An OEIS b-file.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BFile<V> {
+    comments: Vec<String>,
+    entries: Vec<(i64, V)>,
+}
+
+/// A mismatch between a b-file and a sequence.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Mismatch<V> {
+    /// Index of the element in the b-file.
+    pub n: i64,
+    /// Index of the element in the sequence.
+    pub index: usize,
+    /// Value listed in the b-file.
+    pub expected: V,
+    /// Value generated by the sequence.
+    pub actual: V,
+}
+
+/// Result of the verification of a sequence against a b-file.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Verification<V> {
+    matched: usize,
+    mismatch: Option<Mismatch<V>>,
+}
+
+impl<V> Verification<V> {
+    /// Returns the number of entries that matched before the first mismatch (if any).
+    pub fn matched(&self) -> usize {
+        self.matched
+    }
+
+    /// Returns the first mismatch.
+    pub fn mismatch(&self) -> Option<&Mismatch<V>> {
+        self.mismatch.as_ref()
+    }
+
+    /// Checks if all the entries matched.
+    pub fn is_match(&self) -> bool {
+        self.mismatch.is_none()
+    }
+}
+
+impl<V> BFile<V> {
+    /// Creates an empty b-file.
+    pub fn new() -> Self {
+        Self {
+            comments: Vec::new(),
+            entries: Vec::new(),
+        }
+    }
+
+    /// Returns the comments (without the leading `#`).
+    pub fn comments(&self) -> &[String] {
+        &self.comments
+    }
+
+    /// Returns the `(n, a(n))` entries.
+    pub fn entries(&self) -> &[(i64, V)] {
+        &self.entries
+    }
+
+    /// Returns the index of the first entry, which is the offset
+    /// of the sequence for complete b-files.
+    pub fn offset(&self) -> Option<i64> {
+        self.entries.first().map(|(n, _)| *n)
+    }
+
+    /// Adds a comment.
+    pub fn push_comment(&mut self, comment: impl Into<String>) {
+        self.comments.push(comment.into());
+    }
+
+    /// Adds an entry.
+    pub fn push_entry(&mut self, n: i64, value: V) {
+        self.entries.push((n, value));
+    }
+}
+
+impl<V> Default for BFile<V> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<V: FromStr> BFile<V> {
+    /// Reads a b-file.
+    pub fn read(reader: impl BufRead) -> Result<Self, BFileError> {
+        let mut bfile = Self::new();
+
+        for (line_index, line) in reader.lines().enumerate() {
+            bfile.parse_line(&line?, line_index + 1)?;
+        }
+
+        Ok(bfile)
+    }
+
+    /// Parses a line of a b-file.
+    fn parse_line(&mut self, line: &str, line_number: usize) -> Result<(), BFileError> {
+        let line = line.trim();
+
+        if let Some(comment) = line.strip_prefix('#') {
+            self.push_comment(comment.trim());
+            return Ok(());
+        }
+
+        if line.is_empty() {
+            return Ok(());
+        }
+
+        let mut columns = line.split_whitespace();
+        let (Some(n), Some(value), None) = (columns.next(), columns.next(), columns.next()) else {
+            return Err(BFileError::InvalidLine { line: line_number });
+        };
+
+        let n = n
+            .parse()
+            .map_err(|_| BFileError::InvalidIndex { line: line_number })?;
+        let value = value
+            .parse()
+            .map_err(|_| BFileError::InvalidValue { line: line_number })?;
+
+        self.push_entry(n, value);
+
+        Ok(())
+    }
+
+    /// Verifies the sequence against the entries of the b-file, the offset is the index `n`
+    /// of the first element of the sequence. The elements are generated with `nth_element`,
+    /// and the verification stops at the first mismatch.
+    pub fn verify<I>(
+        &self,
+        sequence: &mut Sequence<V, I, WithTransitionFunction<V, I>>,
+        offset: i64,
+    ) -> Result<Verification<V>, BFileError>
+    where
+        V: PartialEq + Clone,
+    {
+        for (matched, (n, expected)) in self.entries.iter().enumerate() {
+            let index = n
+                .checked_sub(offset)
+                .and_then(|index| usize::try_from(index).ok())
+                .ok_or(BFileError::IndexBeforeOffset { n: *n, offset })?;
+            let actual = sequence.try_nth_element(index)?;
+
+            if actual != expected {
+                return Ok(Verification {
+                    matched,
+                    mismatch: Some(Mismatch {
+                        n: *n,
+                        index,
+                        expected: expected.clone(),
+                        actual: actual.clone(),
+                    }),
+                });
+            }
+        }
+
+        Ok(Verification {
+            matched: self.entries.len(),
+            mismatch: None,
+        })
+    }
+}
+
+impl<V: FromStr> FromStr for BFile<V> {
+    type Err = BFileError;
+
+    fn from_str(text: &str) -> Result<Self, Self::Err> {
+        Self::read(text.as_bytes())
+    }
+}
+
+impl<V: Clone> BFile<V> {
+    /// Creates a b-file from the range of the sequence (start inclusive, end exclusive),
+    /// the offset is the index `n` of the first element of the sequence.
+    pub fn from_sequence<I>(
+        sequence: &mut Sequence<V, I, WithTransitionFunction<V, I>>,
+        start: usize,
+        end: usize,
+        offset: i64,
+    ) -> Result<Self, BFileError> {
+        let mut bfile = Self::new();
+
+        for index in start..end {
+            let value = sequence.try_nth_element(index)?.clone();
+            bfile.push_entry(index as i64 + offset, value);
+        }
+
+        Ok(bfile)
+    }
+}
+
+impl<V: Display> BFile<V> {
+    /// Writes the b-file, the comments are written first.
+    pub fn write(&self, mut writer: impl Write) -> Result<(), BFileError> {
+        for comment in &self.comments {
+            writeln!(writer, "# {comment}")?;
+        }
+
+        for (n, value) in &self.entries {
+            write_entry(&mut writer, *n, value)?;
+        }
+
+        Ok(())
+    }
+}
+
+/// Writes the range of the sequence (start inclusive, end exclusive) in the b-file format
+/// without collecting it, the offset is the index `n` of the first element of the sequence.
+pub fn write_range<V: Display, I>(
+    mut writer: impl Write,
+    sequence: &mut Sequence<V, I, WithTransitionFunction<V, I>>,
+    start: usize,
+    end: usize,
+    offset: i64,
+) -> Result<(), BFileError> {
+    for index in start..end {
+        write_entry(
+            &mut writer,
+            index as i64 + offset,
+            sequence.try_nth_element(index)?,
+        )?;
+    }
+
+    Ok(())
+}
+
+/// Writes an `n a(n)` line.
+pub fn write_entry(writer: &mut impl Write, n: i64, value: &impl Display) -> std::io::Result<()> {
+    writeln!(writer, "{n} {value}")
+}
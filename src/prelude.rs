@@ -3,8 +3,24 @@
 use crate::sequence::{states::*, types::*};
 
 pub use crate::{
-    sequence::{Sequence, SharedSequenceBehavior},
-    sequence_part::{error::RangeError, SequencePart, SharedSequencePartBehavior},
+    sequence::{
+        budget::{CancellationToken, GenerationBudget, GenerationProgress, GenerationStop},
+        checkpoint::CheckpointReport,
+        comparison::{ComparisonReport, PrefixComparison},
+        cursor::Cursor,
+        error::GenerationError,
+        index::{FenwickTree, PrefixSums, SparseTable},
+        merge::MonotoneMerge,
+        observer::{SequenceObserver, ThroughputObserver, ThroughputStats},
+        states::ReplacementMode,
+        system::SequenceSystem,
+        Sequence, SharedSequenceBehavior,
+    },
+    sequence_part::{
+        error::RangeError,
+        statistics::{Histogram, QuantileSketch, StreamingStatistic, Summary},
+        SequencePart, SharedSequencePartBehavior,
+    },
 };
 
 /// Create new sequence that do not require initial elements
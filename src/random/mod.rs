@@ -0,0 +1,229 @@
+//! This module defines seeded random streams and sequences of random samples.
+//!
+//! The streams are counter-based (SplitMix-style): the nth sample is a hash of the seed,
+//! the stream and n, so any sample can be computed directly without the preceding ones,
+//! and the same seed always gives the same samples.
+
+pub mod process;
+
+use crate::sequence::{types::DerivedSequence, Sequence};
+
+/// A seeded counter-based random stream.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct RandomStream {
+    seed: u64,
+    key: u64,
+}
+
+/// A probability distribution that maps the words of a random stream to samples.
+pub trait Distribution {
+    /// Type of the samples.
+    type Sample;
+
+    /// Returns the sample of the distribution at the index of the stream.
+    fn sample(&self, stream: &RandomStream, index: usize) -> Self::Sample;
+}
+
+/// Uniform distribution of floats in `[low, high)`.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Uniform {
+    low: f64,
+    high: f64,
+}
+
+/// Uniform distribution of integers in `[low, high]`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct UniformInt {
+    low: i64,
+    high: i64,
+}
+
+/// Normal distribution (sampled with the Box-Muller transform).
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Normal {
+    mean: f64,
+    std_dev: f64,
+}
+
+/// Discrete distribution over the indices of a list of weights.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Discrete {
+    cumulative_weights: Vec<f64>,
+}
+
+impl RandomStream {
+    /// Creates the main stream of the seed.
+    pub fn new(seed: u64) -> Self {
+        Self {
+            seed,
+            key: mix(seed),
+        }
+    }
+
+    /// Returns the seed the stream was derived from.
+    pub fn seed(&self) -> u64 {
+        self.seed
+    }
+
+    /// Returns an independent stream derived from this stream and the id,
+    /// sub-streams can be derived from sub-streams.
+    pub fn substream(&self, id: u64) -> Self {
+        Self {
+            seed: self.seed,
+            key: mix(self.key ^ mix(id.wrapping_add(GOLDEN_GAMMA))),
+        }
+    }
+
+    /// Returns the random word at the index of the stream.
+    pub fn word(&self, index: usize) -> u64 {
+        self.lane_word(index, 0)
+    }
+
+    /// Returns the random float in `[0, 1)` at the index of the stream.
+    pub fn unit(&self, index: usize) -> f64 {
+        to_unit(self.word(index))
+    }
+
+    /// Returns the sample of the distribution at the index of the stream.
+    pub fn sample<D: Distribution>(&self, distribution: &D, index: usize) -> D::Sample {
+        distribution.sample(self, index)
+    }
+
+    /// Returns the sequence of the samples of the distribution, `a(n) = sample(n)`.
+    /// The sequence memoises the samples like any other sequence, a sample reads no
+    /// preceding samples so `bounded_storage` makes it keep only the last ones.
+    /// `sample` is the cheaper way to read a single far away sample.
+    pub fn sequence<D: Distribution + Send + 'static>(
+        self,
+        distribution: D,
+    ) -> DerivedSequence<D::Sample> {
+        Sequence::new().boxed_transition_function(
+            Box::new(move |_, current_element_index| {
+                Ok(distribution.sample(&self, current_element_index))
+            }),
+            Some(0),
+        )
+    }
+
+    /// Returns one of the independent random words at the index of the stream,
+    /// distributions that need several words per sample use different lanes.
+    fn lane_word(&self, index: usize, lane: u64) -> u64 {
+        mix(self.key ^ mix((index as u64).wrapping_mul(LANES).wrapping_add(lane)))
+    }
+}
+
+impl Uniform {
+    /// Creates the distribution, returns None if the bounds are not finite or `low >= high`.
+    pub fn new(low: f64, high: f64) -> Option<Self> {
+        (low.is_finite() && high.is_finite() && low < high).then_some(Self { low, high })
+    }
+}
+
+impl Distribution for Uniform {
+    type Sample = f64;
+
+    fn sample(&self, stream: &RandomStream, index: usize) -> f64 {
+        let sample = self.low + (self.high - self.low) * stream.unit(index);
+
+        // Rounding can reach the excluded upper bound.
+        if sample < self.high {
+            sample
+        } else {
+            self.low
+        }
+    }
+}
+
+impl UniformInt {
+    /// Creates the distribution, returns None if `low > high`.
+    pub fn new(low: i64, high: i64) -> Option<Self> {
+        (low <= high).then_some(Self { low, high })
+    }
+}
+
+impl Distribution for UniformInt {
+    type Sample = i64;
+
+    /// The word is scaled to the range, the bias is at most 2^-64 per value.
+    fn sample(&self, stream: &RandomStream, index: usize) -> i64 {
+        let span = (self.high as i128 - self.low as i128) as u128 + 1;
+        let offset = (stream.word(index) as u128 * span) >> 64;
+
+        (self.low as i128 + offset as i128) as i64
+    }
+}
+
+impl Normal {
+    /// Creates the distribution, returns None if the parameters are not finite
+    /// or the standard deviation is negative.
+    pub fn new(mean: f64, std_dev: f64) -> Option<Self> {
+        (mean.is_finite() && std_dev.is_finite() && std_dev >= 0.0)
+            .then_some(Self { mean, std_dev })
+    }
+}
+
+impl Distribution for Normal {
+    type Sample = f64;
+
+    fn sample(&self, stream: &RandomStream, index: usize) -> f64 {
+        // 1 - unit is in (0, 1], so the logarithm is finite.
+        let radius = (-2.0 * (1.0 - to_unit(stream.lane_word(index, 0))).ln()).sqrt();
+        let angle = std::f64::consts::TAU * to_unit(stream.lane_word(index, 1));
+
+        self.mean + self.std_dev * radius * angle.cos()
+    }
+}
+
+impl Discrete {
+    /// Creates the distribution, the probability of an index is proportional to its weight.
+    /// Returns None if there are no weights, a weight is negative or not finite,
+    /// or all the weights are zero.
+    pub fn new(weights: &[f64]) -> Option<Self> {
+        let mut cumulative_weights = Vec::with_capacity(weights.len());
+        let mut total = 0.0;
+
+        for weight in weights {
+            if !weight.is_finite() || *weight < 0.0 {
+                return None;
+            }
+
+            total += weight;
+            cumulative_weights.push(total);
+        }
+
+        (total > 0.0 && total.is_finite()).then_some(Self { cumulative_weights })
+    }
+}
+
+impl Distribution for Discrete {
+    type Sample = usize;
+
+    fn sample(&self, stream: &RandomStream, index: usize) -> usize {
+        let total = self.cumulative_weights[self.cumulative_weights.len() - 1];
+        let target = total * stream.unit(index);
+
+        self.cumulative_weights
+            .partition_point(|cumulative_weight| *cumulative_weight <= target)
+            .min(self.cumulative_weights.len() - 1)
+    }
+}
+
+/// The increment of SplitMix64.
+const GOLDEN_GAMMA: u64 = 0x9E37_79B9_7F4A_7C15;
+
+/// Number of independent words per index.
+const LANES: u64 = 2;
+
+/// The SplitMix64 output function.
+fn mix(value: u64) -> u64 {
+    let mut z = value.wrapping_add(GOLDEN_GAMMA);
+    z = (z ^ (z >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+    z = (z ^ (z >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+
+    z ^ (z >> 31)
+}
+
+/// Converts a word to a float in `[0, 1)` using its 53 high bits.
+fn to_unit(word: u64) -> f64 {
+    (word >> 11) as f64 * (1.0 / (1_u64 << 53) as f64)
+}
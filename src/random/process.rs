@@ -0,0 +1,175 @@
+//! This module defines stochastic processes (Markov chains, random walks and
+//! autoregressive processes) as sequences. The starting states are the initial elements
+//! of the sequence, and the randomness of the nth element is the nth sample of the stream,
+//! so a process with the same seed always generates the same elements.
+
+use super::{Discrete, Distribution, RandomStream};
+
+use crate::{
+    number::Number,
+    sequence::{
+        error::GenerationError,
+        states::{WithInitialElements, WithTransitionFunction, WithoutTransitionFunction},
+        types::GenerationResult,
+        Sequence,
+    },
+    sequence_part::types::AliveElementsPart,
+};
+
+/// A type that represents a transition function that uses randomness.
+/// The first two parameters are the same as the transition function ones,
+/// and the third is the random stream of the process.
+pub type StochasticTransitionFunction<T> =
+    fn(AliveElementsPart<'_, T, WithInitialElements>, usize, &RandomStream) -> T;
+
+/// A type that represents a sequence generated by a stochastic process.
+pub type ProcessSequence<T> =
+    Sequence<T, WithInitialElements, WithTransitionFunction<T, WithInitialElements>>;
+
+/// Transition matrix of a Markov chain, the row of a state holds
+/// the weights of the next states.
+#[derive(Debug, Clone, PartialEq)]
+pub struct TransitionMatrix {
+    rows: Vec<Discrete>,
+}
+
+impl TransitionMatrix {
+    /// Creates a transition matrix, the weights of a row do not have to sum to 1.
+    /// Returns None if the matrix is not square or a row is not a valid discrete distribution.
+    pub fn new(rows: &[Vec<f64>]) -> Option<Self> {
+        rows.iter()
+            .map(|row| {
+                if row.len() == rows.len() {
+                    Discrete::new(row)
+                } else {
+                    None
+                }
+            })
+            .collect::<Option<_>>()
+            .map(|rows| Self { rows })
+    }
+
+    /// Returns the number of states.
+    pub fn states_len(&self) -> usize {
+        self.rows.len()
+    }
+}
+
+impl<T: 'static> Sequence<T, WithInitialElements, WithoutTransitionFunction> {
+    /// Adds a transition function that uses the random stream to the sequence.
+    pub fn stochastic_transition_function(
+        self,
+        stream: RandomStream,
+        trans_func: StochasticTransitionFunction<T>,
+    ) -> ProcessSequence<T> {
+        self.boxed_transition_function(
+            Box::new(move |alive_elements, current_element_index| {
+                Ok(trans_func(alive_elements, current_element_index, &stream))
+            }),
+            None,
+        )
+    }
+}
+
+impl Sequence<usize, WithInitialElements, WithoutTransitionFunction> {
+    /// Makes the sequence a Markov chain whose states are the indices of the matrix,
+    /// the last initial element is the starting state.
+    pub fn markov_chain(
+        self,
+        matrix: TransitionMatrix,
+        stream: RandomStream,
+    ) -> ProcessSequence<usize> {
+        self.boxed_transition_function(
+            Box::new(move |alive_elements, current_element_index| {
+                let state = *previous_element(&alive_elements, current_element_index)?;
+                let row = matrix
+                    .rows
+                    .get(state)
+                    .ok_or_else(|| GenerationError::Failed {
+                        index: current_element_index,
+                        message: format!(
+                            "The state {state} is not a state of the transition matrix."
+                        ),
+                    })?;
+
+                Ok(stream.sample(row, current_element_index))
+            }),
+            Some(1),
+        )
+    }
+}
+
+impl<T: Number + Send + 'static> Sequence<T, WithInitialElements, WithoutTransitionFunction> {
+    /// Makes the sequence a random walk whose steps are samples of the distribution,
+    /// the last initial element is the starting position.
+    pub fn random_walk<D: Distribution<Sample = T> + Send + 'static>(
+        self,
+        steps: D,
+        stream: RandomStream,
+    ) -> ProcessSequence<T> {
+        self.boxed_transition_function(
+            Box::new(move |alive_elements, current_element_index| {
+                let position = previous_element(&alive_elements, current_element_index)?;
+
+                position
+                    .checked_add(&stream.sample(&steps, current_element_index))
+                    .ok_or_else(|| GenerationError::Failed {
+                        index: current_element_index,
+                        message: String::from("The position is not representable."),
+                    })
+            }),
+            Some(1),
+        )
+    }
+}
+
+impl Sequence<f64, WithInitialElements, WithoutTransitionFunction> {
+    /// Makes the sequence an autoregressive process of order p (the number of coefficients),
+    /// `x(n) = c(1) x(n - 1) + ... + c(p) x(n - p) + e(n)` where `e(n)` is a sample of the noise.
+    /// The initial elements must hold at least p elements.
+    pub fn autoregressive<D: Distribution<Sample = f64> + Send + 'static>(
+        self,
+        coefficients: Vec<f64>,
+        noise: D,
+        stream: RandomStream,
+    ) -> ProcessSequence<f64> {
+        let order = coefficients.len();
+
+        self.boxed_transition_function(
+            Box::new(move |alive_elements, current_element_index| {
+                if current_element_index < order {
+                    return Err(GenerationError::Failed {
+                        index: current_element_index,
+                        message: format!(
+                            "The AR({order}) process requires {order} initial elements."
+                        ),
+                    });
+                }
+
+                let mut element = stream.sample(&noise, current_element_index);
+
+                for (lag, coefficient) in coefficients.iter().enumerate() {
+                    element += coefficient
+                        * alive_elements.try_nth_element(current_element_index - lag - 1)?;
+                }
+
+                Ok(element)
+            }),
+            Some(order),
+        )
+    }
+}
+
+/// Returns the element that precedes the current one.
+fn previous_element<'a, T>(
+    alive_elements: &'a AliveElementsPart<'_, T, WithInitialElements>,
+    current_element_index: usize,
+) -> GenerationResult<&'a T> {
+    match current_element_index.checked_sub(1) {
+        Some(index) => alive_elements.try_nth_element(index),
+        None => Err(GenerationError::Failed {
+            index: current_element_index,
+            message: String::from("The process requires a starting state."),
+        }),
+    }
+}
@@ -0,0 +1,153 @@
+//! This module defines the generation budget that limits
+//! how long a generation of elements can run
+
+use std::{
+    sync::{
+        atomic::{AtomicBool, Ordering},
+        Arc,
+    },
+    time::{Duration, Instant},
+};
+
+/// A token that can be used to cancel a generation from another thread.
+/// Clones of a token share the same cancellation state.
+#[derive(Debug, Clone, Default)]
+pub struct CancellationToken(Arc<AtomicBool>);
+
+impl CancellationToken {
+    /// Creates a new token that is not cancelled.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Cancels the generations that use this token.
+    pub fn cancel(&self) {
+        self.0.store(true, Ordering::Relaxed);
+    }
+
+    /// Checks if the token was cancelled.
+    pub fn is_cancelled(&self) -> bool {
+        self.0.load(Ordering::Relaxed)
+    }
+}
+
+/// Limits of a generation, a generation stops
+/// when any of its limits is hit.
+#[derive(Debug, Clone, Default)]
+pub struct GenerationBudget {
+    cancellation_token: Option<CancellationToken>,
+    deadline: Option<Instant>,
+    max_steps: Option<usize>,
+}
+
+impl GenerationBudget {
+    /// Creates a new budget without limits.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Stops the generation when the token is cancelled.
+    pub fn cancellation_token(mut self, cancellation_token: CancellationToken) -> Self {
+        self.cancellation_token = Some(cancellation_token);
+        self
+    }
+
+    /// Stops the generation when the deadline is reached, the deadline is checked
+    /// before each generated element (or block of a batched transition).
+    pub fn deadline(mut self, deadline: Instant) -> Self {
+        self.deadline = Some(deadline);
+        self
+    }
+
+    /// Stops the generation when the duration elapses (starting from now).
+    pub fn time_budget(self, duration: Duration) -> Self {
+        self.deadline(Instant::now() + duration)
+    }
+
+    /// Stops the generation after the specified number of generated elements,
+    /// the blocks of batched transitions are cut short at the limit.
+    pub fn max_steps(mut self, max_steps: usize) -> Self {
+        self.max_steps = Some(max_steps);
+        self
+    }
+
+    /// Returns the number of steps left before the step limit.
+    pub(crate) fn remaining_steps(&self, steps: usize) -> usize {
+        self.max_steps
+            .map_or(usize::MAX, |max_steps| max_steps.saturating_sub(steps))
+    }
+
+    /// Returns the reason to stop the generation after the specified
+    /// number of steps, or None if the budget is not exhausted.
+    pub(crate) fn exhausted(&self, steps: usize) -> Option<GenerationStop> {
+        if let Some(cancellation_token) = &self.cancellation_token {
+            if cancellation_token.is_cancelled() {
+                return Some(GenerationStop::Cancelled);
+            }
+        }
+
+        if let Some(max_steps) = self.max_steps {
+            if steps >= max_steps {
+                return Some(GenerationStop::StepLimitReached);
+            }
+        }
+
+        if let Some(deadline) = self.deadline {
+            if Instant::now() >= deadline {
+                return Some(GenerationStop::DeadlineReached);
+            }
+        }
+
+        None
+    }
+}
+
+/// The reason a generation stopped before generating all the requested elements.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum GenerationStop {
+    /// The cancellation token was cancelled.
+    Cancelled,
+    /// The deadline was reached.
+    DeadlineReached,
+    /// The maximum number of steps was reached.
+    StepLimitReached,
+}
+
+/// Progress of a generation, it tells how far the generation got.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct GenerationProgress {
+    generated: usize,
+    next_index: usize,
+    stop: Option<GenerationStop>,
+}
+
+impl GenerationProgress {
+    /// Create new instance.
+    pub(crate) fn new(generated: usize, next_index: usize, stop: Option<GenerationStop>) -> Self {
+        Self {
+            generated,
+            next_index,
+            stop,
+        }
+    }
+
+    /// Returns the number of elements generated by the generation.
+    pub fn generated(&self) -> usize {
+        self.generated
+    }
+
+    /// Returns the index of the next element to be generated.
+    pub fn next_index(&self) -> usize {
+        self.next_index
+    }
+
+    /// Returns the reason the generation stopped early, or None if it completed.
+    pub fn stop(&self) -> Option<GenerationStop> {
+        self.stop
+    }
+
+    /// Checks if all the requested elements were generated.
+    pub fn is_complete(&self) -> bool {
+        self.stop.is_none()
+    }
+}
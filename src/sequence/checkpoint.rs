@@ -0,0 +1,325 @@
+//! This module defines the checkpoints of sequences, a checkpoint is the window
+//! of elements the transition looks back at, recorded at regular intervals.
+//! Any segment of the sequence can be recomputed from the checkpoint that precedes it,
+//! so the sequence does not need to keep its whole prefix (see `bounded_storage`).
+
+use std::{collections::BTreeMap, mem, ops::Range};
+
+use super::{
+    error::GenerationError,
+    parallel::map_in_parallel,
+    states::{WithInitialElements, WithTransitionFunction},
+    types::{GenerationResult, RecurrenceFunction},
+    Override, Sequence,
+};
+
+/// The checkpoints recorded on a sequence.
+pub(super) struct Checkpoints<T> {
+    interval: usize,
+    window_len: usize,
+    /// The index of each checkpoint with the elements that precede it (ordered by index).
+    windows: Vec<(usize, Vec<T>)>,
+    clone: fn(&T) -> T,
+}
+
+/// Report of the verification of the checkpoints of a sequence.
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct CheckpointReport {
+    segments: usize,
+    mismatches: Vec<Range<usize>>,
+}
+
+impl<T> Checkpoints<T> {
+    /// Checks if a checkpoint is due at the index.
+    fn is_due(&self, index: usize) -> bool {
+        index.is_multiple_of(self.interval)
+            && self.windows.last().is_some_and(|(last, _)| *last < index)
+    }
+
+    /// Returns the nearest checkpoint at or before the index.
+    fn nearest(&self, index: usize) -> Option<&(usize, Vec<T>)> {
+        let end = self
+            .windows
+            .partition_point(|(checkpoint, _)| *checkpoint <= index);
+        self.windows[..end].last()
+    }
+
+    /// Removes the checkpoints whose windows reach the index `len` or beyond.
+    pub(super) fn truncate(&mut self, len: usize) {
+        let end = self
+            .windows
+            .partition_point(|(checkpoint, _)| *checkpoint <= len);
+        self.windows.truncate(end);
+    }
+}
+
+impl<T, I> Sequence<T, I, WithTransitionFunction<T, I>> {
+    /// Records a checkpoint at the index if one is due, the elements
+    /// of the window must be alive.
+    pub(super) fn record_checkpoint(&mut self, index: usize) {
+        let Some(checkpoints) = &self.checkpoints else {
+            return;
+        };
+
+        if !checkpoints.is_due(index) {
+            return;
+        }
+
+        let window = (index - checkpoints.window_len..index)
+            .map(|index| {
+                self.nth_element_without_generation(index)
+                    .map(checkpoints.clone)
+            })
+            .collect::<Option<Vec<_>>>();
+
+        if let (Some(window), Some(checkpoints)) = (window, &mut self.checkpoints) {
+            checkpoints.windows.push((index, window));
+        }
+    }
+
+    /// Returns the checkpoints ordered by index, with the window of elements that precedes them.
+    pub fn checkpoints(&self) -> impl Iterator<Item = (usize, &[T])> {
+        self.checkpoints
+            .iter()
+            .flat_map(|checkpoints| checkpoints.windows.iter())
+            .map(|(index, window)| (*index, window.as_slice()))
+    }
+}
+
+impl<T: Clone> Sequence<T, WithInitialElements, WithTransitionFunction<T, WithInitialElements>> {
+    /// Makes the sequence record a checkpoint every `interval` indices, with the window
+    /// of elements its recurrence looks back at. The initial elements are the first checkpoint.
+    /// This has no effect on sequences that do not declare a look-back.
+    pub fn checkpoint_every(mut self, interval: usize) -> Self {
+        let initial_elements_len = self.initial_elements_len();
+
+        if let Some(look_back) = self
+            .look_back
+            .filter(|look_back| *look_back <= initial_elements_len)
+        {
+            let window = self.alive_elements
+                [initial_elements_len - look_back..initial_elements_len]
+                .to_vec();
+
+            self.checkpoints = Some(Checkpoints {
+                interval: interval.max(1),
+                window_len: look_back,
+                windows: vec![(initial_elements_len, window)],
+                clone: T::clone,
+            });
+
+            for index in initial_elements_len + 1..=self.next_index() {
+                self.record_checkpoint(index);
+            }
+        }
+
+        self
+    }
+
+    /// Recomputes the elements from `start` to `end` (exclusive) from the nearest checkpoint
+    /// at or before `start`, or from the initial elements if there is none. The alive elements,
+    /// the observers and the checkpoints of the sequence are left as they are,
+    /// the panics of the transition function are returned as errors.
+    pub fn recompute_segment(&mut self, start: usize, end: usize) -> GenerationResult<Vec<T>> {
+        if start >= end {
+            return Ok(Vec::new());
+        }
+
+        let (checkpoint, window) = self
+            .checkpoints
+            .as_ref()
+            .and_then(|checkpoints| checkpoints.nearest(start))
+            .map_or(
+                (self.initial_elements_len(), Vec::new()),
+                |(checkpoint, window)| (*checkpoint, window.clone()),
+            );
+
+        self.generate_from_checkpoint(checkpoint, &window, start..end)
+    }
+
+    /// Re-derives the segments between consecutive checkpoints with the transition
+    /// of the sequence, and checks that each segment ends with the window of the next checkpoint.
+    /// The segments are re-derived one after another since the transition can't be shared
+    /// between threads, see `par_verify_checkpoints` to verify them in parallel with a recurrence.
+    /// The panics of the transition function are returned as errors.
+    pub fn verify_checkpoints(&mut self) -> GenerationResult<CheckpointReport>
+    where
+        T: PartialEq,
+    {
+        let Some(checkpoints) = self.checkpoints.take() else {
+            return Ok(CheckpointReport::default());
+        };
+
+        let report = self.verify_windows(&checkpoints.windows);
+        self.checkpoints = Some(checkpoints);
+
+        report
+    }
+
+    /// Re-derives the segments between consecutive windows, see `verify_checkpoints`.
+    fn verify_windows(&mut self, windows: &[(usize, Vec<T>)]) -> GenerationResult<CheckpointReport>
+    where
+        T: PartialEq,
+    {
+        let mut report = CheckpointReport::default();
+
+        for pair in windows.windows(2) {
+            let ((start, window), (end, expected)) = (&pair[0], &pair[1]);
+            let rederived =
+                self.generate_from_checkpoint(*start, window, end - expected.len()..*end)?;
+
+            report.segments += 1;
+
+            if rederived != *expected {
+                report.mismatches.push(*start..*end);
+            }
+        }
+
+        Ok(report)
+    }
+
+    /// Generates the elements of the indices from the window that precedes the checkpoint,
+    /// the indices must not precede the window. The generation runs on a scratch storage
+    /// that holds the initial elements and the window, the elements in between are marked
+    /// as dropped. The state of the sequence is restored even if the generation fails.
+    fn generate_from_checkpoint(
+        &mut self,
+        checkpoint: usize,
+        window: &[T],
+        indices: Range<usize>,
+    ) -> GenerationResult<Vec<T>> {
+        let initial_elements_len = self.initial_elements_len();
+        let window_start = checkpoint - window.len();
+        let mut scratch_elements = self.alive_elements[..initial_elements_len].to_vec();
+        scratch_elements.extend_from_slice(
+            &window[initial_elements_len
+                .saturating_sub(window_start)
+                .min(window.len())..],
+        );
+
+        let alive_elements = mem::replace(&mut self.alive_elements, scratch_elements);
+        let dropped_elements = mem::replace(
+            &mut self.dropped_elements,
+            initial_elements_len..window_start.max(initial_elements_len),
+        );
+        let retained_elements = self.retained_elements.take();
+        let observers = mem::take(&mut self.observers);
+        let checkpoints = self.checkpoints.take();
+
+        // The panics are caught so that the state is always restored.
+        let elements = self
+            .generate_nth_element_with(indices.end - 1, None, true)
+            .map(|_| {
+                indices
+                    .map(|index| {
+                        self.nth_element_without_generation(index)
+                            .expect("The generated elements are alive.")
+                            .clone()
+                    })
+                    .collect()
+            });
+
+        self.alive_elements = alive_elements;
+        self.dropped_elements = dropped_elements;
+        self.retained_elements = retained_elements;
+        self.observers = observers;
+        self.checkpoints = checkpoints;
+
+        elements
+    }
+}
+
+impl<T: Clone + PartialEq + Send + Sync>
+    Sequence<T, WithInitialElements, WithTransitionFunction<T, WithInitialElements>>
+{
+    /// Re-derives the segments between consecutive checkpoints in parallel with the recurrence,
+    /// and checks that each segment ends with the window of the next checkpoint.
+    /// The recurrence should be the one of the sequence (see `verify_checkpoints`
+    /// to use the transition of the sequence). The overridden elements are used as they are.
+    /// Fails if the order of the recurrence is not the look-back of the sequence.
+    pub fn par_verify_checkpoints<const K: usize>(
+        &self,
+        recurrence: RecurrenceFunction<T, K>,
+    ) -> GenerationResult<CheckpointReport> {
+        let Some(checkpoints) = &self.checkpoints else {
+            return Ok(CheckpointReport::default());
+        };
+
+        let windows = &checkpoints.windows;
+
+        if K != checkpoints.window_len {
+            return Err(GenerationError::Failed {
+                index: windows[0].0,
+                message: format!(
+                    "The order of the recurrence ({K}) must be the look-back of the sequence ({}).",
+                    checkpoints.window_len
+                ),
+            });
+        }
+
+        let overrides = &self.overrides;
+        let segments = windows.len() - 1;
+
+        let matches = map_in_parallel(0..segments, |segment| {
+            let (start, window) = &windows[segment];
+            let (end, expected) = &windows[segment + 1];
+
+            rederive_window(recurrence, overrides, *start..*end, window) == *expected
+        });
+
+        Ok(CheckpointReport {
+            segments,
+            mismatches: windows
+                .windows(2)
+                .zip(matches)
+                .filter(|(_, matches)| !matches)
+                .map(|(pair, _)| pair[0].0..pair[1].0)
+                .collect(),
+        })
+    }
+}
+
+/// Generates the elements of the indices with the recurrence from the window
+/// that precedes them, and returns the window that follows them.
+fn rederive_window<T: Clone, const K: usize>(
+    recurrence: RecurrenceFunction<T, K>,
+    overrides: &BTreeMap<usize, Override<T>>,
+    indices: Range<usize>,
+    window: &[T],
+) -> Vec<T> {
+    let mut elements = window.to_vec();
+
+    for index in indices {
+        let element = match overrides.get(&index) {
+            Some((element, _)) => element.clone(),
+            None => recurrence(
+                std::array::from_fn(|offset| &elements[elements.len() - K + offset]),
+                index,
+            ),
+        };
+        elements.push(element);
+
+        if elements.len() >= 2 * K.max(1) {
+            elements.drain(..elements.len() - K);
+        }
+    }
+
+    elements.split_off(elements.len() - K)
+}
+
+impl CheckpointReport {
+    /// Returns the number of verified segments.
+    pub fn segments(&self) -> usize {
+        self.segments
+    }
+
+    /// Returns the segments that do not end with the window of the next checkpoint.
+    pub fn mismatches(&self) -> &[Range<usize>] {
+        &self.mismatches
+    }
+
+    /// Checks if all the verified segments match.
+    pub fn is_consistent(&self) -> bool {
+        self.mismatches.is_empty()
+    }
+}
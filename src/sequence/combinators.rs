@@ -0,0 +1,118 @@
+//! This module defines combinators of sequences (map, zip, etc...),
+//! the combined sequences own their sources and are memoised like any other sequence.
+//! The combinators that have an Iterator adapter counterpart end with `_seq`
+//! (e.g. `map_seq`) so they do not shadow the adapters. Unlike the adapters, they
+//! do not continue the iteration, the combined sequences start from the index 0.
+
+use std::cell::RefCell;
+
+use super::{states::WithTransitionFunction, types::DerivedSequence, Sequence};
+
+impl<T: Send + 'static, I: Send + 'static> Sequence<T, I, WithTransitionFunction<T, I>> {
+    /// Returns the sequence of the results of the function on the elements, `b(n) = f(a(n))`.
+    pub fn map_seq<U>(self, f: impl Fn(&T) -> U + Send + 'static) -> DerivedSequence<U> {
+        self.derive(move |source, _, current_element_index| {
+            source.try_nth_element(current_element_index).map(&f)
+        })
+    }
+
+    /// Returns the sequence of the results of the function on the elements
+    /// of both sequences, `c(n) = f(a(n), b(n))`.
+    pub fn zip_with_seq<U: Send + 'static, J: Send + 'static, V>(
+        self,
+        other: Sequence<U, J, WithTransitionFunction<U, J>>,
+        f: impl Fn(&T, &U) -> V + Send + 'static,
+    ) -> DerivedSequence<V> {
+        let other = RefCell::new(other);
+
+        self.derive(move |source, _, current_element_index| {
+            let mut other = other.borrow_mut();
+
+            Ok(f(
+                source.try_nth_element(current_element_index)?,
+                other.try_nth_element(current_element_index)?,
+            ))
+        })
+    }
+
+    /// Returns the sequence of the composition, `b(n) = a(c(n))`
+    /// where `c` is the sequence of indices.
+    pub fn compose<J: Send + 'static>(
+        self,
+        indices: Sequence<usize, J, WithTransitionFunction<usize, J>>,
+    ) -> DerivedSequence<T>
+    where
+        T: Clone,
+    {
+        let indices = RefCell::new(indices);
+
+        self.derive(move |source, _, current_element_index| {
+            let index = *indices
+                .borrow_mut()
+                .try_nth_element(current_element_index)?;
+
+            source.try_nth_element(index).cloned()
+        })
+    }
+}
+
+impl<T: Clone + Send + 'static, I: Send + 'static> Sequence<T, I, WithTransitionFunction<T, I>> {
+    /// Returns the sequence of the pairs of the elements of both sequences, `c(n) = (a(n), b(n))`.
+    pub fn zip_seq<U: Clone + Send + 'static, J: Send + 'static>(
+        self,
+        other: Sequence<U, J, WithTransitionFunction<U, J>>,
+    ) -> DerivedSequence<(T, U)> {
+        self.zip_with_seq(other, |element, other_element| {
+            (element.clone(), other_element.clone())
+        })
+    }
+
+    /// Returns the sequence of the pairs of the indices and the elements, `b(n) = (n, a(n))`.
+    pub fn enumerate_seq(self) -> DerivedSequence<(usize, T)> {
+        self.derive(|source, _, current_element_index| {
+            let element = source.try_nth_element(current_element_index)?;
+
+            Ok((current_element_index, element.clone()))
+        })
+    }
+
+    /// Returns the sequence that alternates between the elements of both sequences,
+    /// `c(2n) = a(n)` and `c(2n + 1) = b(n)`.
+    pub fn interleave<J: Send + 'static>(
+        self,
+        other: Sequence<T, J, WithTransitionFunction<T, J>>,
+    ) -> DerivedSequence<T> {
+        let other = RefCell::new(other);
+
+        self.derive(move |source, _, current_element_index| {
+            let index = current_element_index / 2;
+
+            if current_element_index.is_multiple_of(2) {
+                source.try_nth_element(index).cloned()
+            } else {
+                other.borrow_mut().try_nth_element(index).cloned()
+            }
+        })
+    }
+
+    /// Returns the sequence of the first `prefix_len` elements of this sequence
+    /// followed by the elements of the other sequence (from its first element).
+    pub fn concat<J: Send + 'static>(
+        self,
+        prefix_len: usize,
+        other: Sequence<T, J, WithTransitionFunction<T, J>>,
+    ) -> DerivedSequence<T> {
+        let other = RefCell::new(other);
+
+        self.derive(move |source, _, current_element_index| {
+            if current_element_index < prefix_len {
+                source.try_nth_element(current_element_index).cloned()
+            } else {
+                other
+                    .borrow_mut()
+                    .try_nth_element(current_element_index - prefix_len)
+                    .cloned()
+            }
+        })
+    }
+}
@@ -0,0 +1,161 @@
+//! This module defines the comparison of the prefixes of two sequences
+
+use std::ops::Range;
+
+use super::{
+    states::{InitialElementsState, WithTransitionFunction},
+    types::GenerationResult,
+    Sequence,
+};
+
+/// Number of compared elements between two drops of the compared elements.
+const DROP_INTERVAL: usize = 1024;
+
+/// Comparison of the prefixes of two sequences, the sequences are generated in lockstep.
+/// The sequences keep all their compared elements unless `drop_compared` is used.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct PrefixComparison {
+    len: usize,
+    until_divergence: bool,
+    drop_compared: bool,
+}
+
+/// Report of the comparison of the prefixes of two sequences.
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct ComparisonReport {
+    compared: usize,
+    differences: usize,
+    first_difference: Option<usize>,
+    agreement_runs: Vec<Range<usize>>,
+}
+
+impl PrefixComparison {
+    /// Creates a comparison of the first `len` elements of the sequences.
+    pub fn new(len: usize) -> Self {
+        Self {
+            len,
+            until_divergence: false,
+            drop_compared: false,
+        }
+    }
+
+    /// Stops the comparison at the first difference.
+    pub fn until_divergence(mut self) -> Self {
+        self.until_divergence = true;
+        self
+    }
+
+    /// Drops the compared elements of the sequences that declare a look-back
+    /// (e.g. a `recurrence`) as the comparison goes, except the window they look back at,
+    /// the dropped elements can't be read afterwards. The other sequences keep their elements.
+    pub fn drop_compared(mut self) -> Self {
+        self.drop_compared = true;
+        self
+    }
+
+    /// Compares the sequences using `PartialEq`.
+    pub fn run<T: PartialEq<U>, I: InitialElementsState, U, J: InitialElementsState>(
+        &self,
+        sequence: &mut Sequence<T, I, WithTransitionFunction<T, I>>,
+        other: &mut Sequence<U, J, WithTransitionFunction<U, J>>,
+    ) -> GenerationResult<ComparisonReport> {
+        self.run_by(sequence, other, |element, other_element| {
+            element == other_element
+        })
+    }
+
+    /// Compares the sequences using a custom equality (e.g. a float tolerance).
+    pub fn run_by<T, I: InitialElementsState, U, J: InitialElementsState>(
+        &self,
+        sequence: &mut Sequence<T, I, WithTransitionFunction<T, I>>,
+        other: &mut Sequence<U, J, WithTransitionFunction<U, J>>,
+        mut eq: impl FnMut(&T, &U) -> bool,
+    ) -> GenerationResult<ComparisonReport> {
+        let mut report = ComparisonReport::default();
+        let mut run_start = None;
+
+        for index in 0..self.len {
+            let equal = eq(
+                sequence.try_nth_element(index)?,
+                other.try_nth_element(index)?,
+            );
+            report.compared += 1;
+
+            if self.drop_compared && report.compared.is_multiple_of(DROP_INTERVAL) {
+                sequence.drop_compared_elements();
+                other.drop_compared_elements();
+            }
+
+            if equal {
+                run_start.get_or_insert(index);
+                continue;
+            }
+
+            if let Some(start) = run_start.take() {
+                report.agreement_runs.push(start..index);
+            }
+
+            report.differences += 1;
+            report.first_difference.get_or_insert(index);
+
+            if self.until_divergence {
+                break;
+            }
+        }
+
+        if let Some(start) = run_start {
+            report.agreement_runs.push(start..report.compared);
+        }
+
+        Ok(report)
+    }
+}
+
+impl<T, I: InitialElementsState> Sequence<T, I, WithTransitionFunction<T, I>> {
+    /// Drops the generated elements except the window the transition looks back at,
+    /// if the sequence declares a look-back.
+    fn drop_compared_elements(&mut self) {
+        if let Some(look_back) = self.look_back {
+            self.retain_last_alive_elements(
+                look_back,
+                self.initial_elements.initial_elements_len(),
+            );
+        }
+    }
+}
+
+impl ComparisonReport {
+    /// Returns the number of compared elements.
+    pub fn compared(&self) -> usize {
+        self.compared
+    }
+
+    /// Returns the number of compared elements that differ.
+    pub fn differences(&self) -> usize {
+        self.differences
+    }
+
+    /// Returns the index of the first element that differs.
+    pub fn first_difference(&self) -> Option<usize> {
+        self.first_difference
+    }
+
+    /// Returns the ranges of indices where the sequences agree.
+    pub fn agreement_runs(&self) -> &[Range<usize>] {
+        &self.agreement_runs
+    }
+
+    /// Returns the length of the longest run of agreement.
+    pub fn longest_agreement(&self) -> usize {
+        self.agreement_runs
+            .iter()
+            .map(|run| run.len())
+            .max()
+            .unwrap_or(0)
+    }
+
+    /// Checks if all the compared elements are equal.
+    pub fn is_equal(&self) -> bool {
+        self.differences == 0
+    }
+}
@@ -0,0 +1,101 @@
+//! This module defines cursors, which walk a shared sequence
+//! with their own position, so several cursors can walk the same sequence
+//! independently while the elements are generated only once.
+
+use std::{cell::RefCell, rc::Rc};
+
+use super::{
+    states::WithTransitionFunction,
+    types::{GenerationResult, SharedSequence},
+    Sequence,
+};
+
+/// A position in a shared sequence, cloning a cursor gives
+/// another cursor at the same position in the same sequence.
+pub struct Cursor<T, I> {
+    sequence: SharedSequence<T, I>,
+    position: usize,
+}
+
+impl<T, I> Sequence<T, I, WithTransitionFunction<T, I>> {
+    /// Moves the sequence into a shared sequence and returns a cursor at its first element.
+    pub fn into_cursor(self) -> Cursor<T, I> {
+        Cursor::new(Rc::new(RefCell::new(self)))
+    }
+}
+
+impl<T, I> Cursor<T, I> {
+    /// Creates a cursor at the first element of the shared sequence.
+    pub fn new(sequence: SharedSequence<T, I>) -> Self {
+        Self {
+            sequence,
+            position: 0,
+        }
+    }
+
+    /// Returns the shared sequence.
+    pub fn sequence(&self) -> &SharedSequence<T, I> {
+        &self.sequence
+    }
+
+    /// Returns the index of the element the cursor is at.
+    pub fn position(&self) -> usize {
+        self.position
+    }
+
+    /// Moves the cursor to the element of the index.
+    pub fn seek(&mut self, position: usize) {
+        self.position = position;
+    }
+
+    /// Moves the cursor forward by the number of elements.
+    pub fn advance_by(&mut self, number_of_elements: usize) {
+        self.position = self.position.saturating_add(number_of_elements);
+    }
+
+    /// Moves the cursor back to the first element.
+    pub fn rewind(&mut self) {
+        self.position = 0;
+    }
+}
+
+impl<T: Clone, I> Cursor<T, I> {
+    /// Returns the element the cursor is at without moving the cursor,
+    /// or the error that prevented it from being generated.
+    pub fn peek(&self) -> GenerationResult<T> {
+        self.sequence
+            .borrow_mut()
+            .try_nth_element(self.position)
+            .cloned()
+    }
+}
+
+impl<T, I> Clone for Cursor<T, I> {
+    fn clone(&self) -> Self {
+        Self {
+            sequence: Rc::clone(&self.sequence),
+            position: self.position,
+        }
+    }
+}
+
+impl<T: Clone, I> Iterator for Cursor<T, I> {
+    type Item = T;
+
+    /// Returns the element the cursor is at and moves the cursor to the next element,
+    /// unlike the sequence iterator the cursor stays at the end once it is reached.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the generation fails or if the element was dropped from storage.
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.position == usize::MAX {
+            return None;
+        }
+
+        let element = self.peek().unwrap_or_else(|error| panic!("{error}"));
+        self.position += 1;
+
+        Some(element)
+    }
+}
@@ -0,0 +1,100 @@
+//! This module defines errors for sequence
+
+use std::{
+    error::Error,
+    fmt::{Debug, Display},
+};
+
+/// Generation error happens when an element of the sequence can not be generated.
+/// Example of this error could be a transition function reading an element
+/// that is not alive while generating another element.
+pub enum GenerationError {
+    /// Represents a read of an element that is not alive.
+    UnavailableElement {
+        /// Index of the element being generated.
+        generating: usize,
+        /// Index of the requested element.
+        requested: usize,
+        /// Number of the alive elements.
+        alive_len: usize,
+    },
+    /// Requested element was dropped from the sequence storage.
+    DroppedElement {
+        /// Index of the requested element.
+        index: usize,
+    },
+    /// The transition function failed to generate an element.
+    Failed {
+        /// Index of the element being generated.
+        index: usize,
+        /// Description of the failure.
+        message: String,
+    },
+    /// The transition function panicked.
+    TransitionPanicked {
+        /// Index of the element being generated.
+        index: usize,
+        /// Message of the panic.
+        message: String,
+    },
+}
+
+impl Debug for GenerationError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            GenerationError::UnavailableElement {
+                generating,
+                requested,
+                alive_len,
+            } => f
+                .debug_struct("UnavailableElement")
+                .field("generating", generating)
+                .field("requested", requested)
+                .field("alive_len", alive_len)
+                .finish(),
+            GenerationError::DroppedElement { index } => f
+                .debug_struct("DroppedElement")
+                .field("index", index)
+                .finish(),
+            GenerationError::Failed { index, message } => f
+                .debug_struct("Failed")
+                .field("index", index)
+                .field("message", message)
+                .finish(),
+            GenerationError::TransitionPanicked { index, message } => f
+                .debug_struct("TransitionPanicked")
+                .field("index", index)
+                .field("message", message)
+                .finish(),
+        }
+    }
+}
+
+impl Display for GenerationError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            GenerationError::UnavailableElement {
+                generating,
+                requested,
+                alive_len,
+            } => write!(
+                f,
+                "Element {requested} is not alive while generating element {generating} ({alive_len} alive elements)."
+            ),
+            GenerationError::DroppedElement { index } => {
+                write!(f, "Element {index} was dropped from the sequence storage.")
+            }
+            GenerationError::Failed { index, message } => {
+                write!(f, "Failed to generate element {index}: {message}")
+            }
+            GenerationError::TransitionPanicked { index, message } => {
+                write!(
+                    f,
+                    "Transition function panicked while generating element {index}: {message}"
+                )
+            }
+        }
+    }
+}
+
+impl Error for GenerationError {}
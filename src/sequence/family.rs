@@ -0,0 +1,162 @@
+//! This module defines parameterised sequences (families of sequences),
+//! where the transition function receives a parameter stored with it,
+//! and a catalogue of parameterised sequences.
+
+use super::{
+    error::GenerationError,
+    states::{InitialElementsState, WithTransitionFunction, WithoutTransitionFunction},
+    types::{DerivedSequence, GenerationResult, ParameterizedTransitionFunction},
+    Sequence,
+};
+
+use crate::number::Number;
+
+impl<T, I: 'static> Sequence<T, I, WithoutTransitionFunction> {
+    /// Adds a transition function that receives the parameter to the sequence,
+    /// the parameter is stored with the transition function and keeps its type.
+    pub fn parameterized_transition_function<P: Send + 'static>(
+        self,
+        parameter: P,
+        trans_func: ParameterizedTransitionFunction<T, I, P>,
+    ) -> Sequence<T, I, WithTransitionFunction<T, I>>
+    where
+        T: 'static,
+    {
+        self.map_states(|initial_elements, _| {
+            (
+                initial_elements,
+                WithTransitionFunction::new_parameterized(parameter, trans_func),
+            )
+        })
+    }
+}
+
+impl<T, I> Sequence<T, I, WithTransitionFunction<T, I>> {
+    /// Returns the parameter of the sequence if it has a parameter of this type
+    /// (the type must be the exact type given to `parameterized_transition_function`).
+    pub fn parameter<P: 'static>(&self) -> Option<&P> {
+        self.trans_func.parameter()
+    }
+}
+
+impl<T, I: InitialElementsState> Sequence<T, I, WithTransitionFunction<T, I>> {
+    /// Replaces the parameter of the sequence and returns the previous one,
+    /// the generated elements are removed so they are generated again with the new parameter.
+    /// The parameter is given back as an error if the sequence has no parameter of this type
+    /// (e.g. an `i32` literal for a `usize` parameter, write `4_usize`).
+    pub fn set_parameter<P: 'static>(&mut self, parameter: P) -> Result<P, P> {
+        let Some(previous) = self.trans_func.parameter_mut() else {
+            return Err(parameter);
+        };
+
+        let previous = std::mem::replace(previous, parameter);
+        self.truncate_alive_elements(0, self.initial_elements.initial_elements_len());
+
+        Ok(previous)
+    }
+}
+
+impl<T: Number + Send + 'static> DerivedSequence<T> {
+    /// Returns the arithmetic sequence `a(n) = start + n * step`,
+    /// the parameter is `(start, step)`.
+    pub fn arithmetic(start: T, step: T) -> Self {
+        Sequence::new().parameterized_transition_function(
+            (start, step),
+            |_, current_element_index, (start, step): &(T, T)| {
+                let element = T::from_integer(current_element_index as i128)
+                    .and_then(|n| n.checked_mul(step))
+                    .and_then(|product| start.checked_add(&product));
+
+                checked(current_element_index, element)
+            },
+        )
+    }
+
+    /// Returns the geometric sequence `a(n) = start * ratio^n`,
+    /// the parameter is `(start, ratio)`.
+    pub fn geometric(start: T, ratio: T) -> Self {
+        Sequence::new().parameterized_transition_function(
+            (start, ratio),
+            |_, current_element_index, (start, ratio): &(T, T)| {
+                let element = T::from_integer(current_element_index as i128)
+                    .and_then(|n| ratio.checked_pow(&n))
+                    .and_then(|power| start.checked_mul(&power));
+
+                checked(current_element_index, element)
+            },
+        )
+    }
+
+    /// Returns the sequence of the values of the polynomial at the indices,
+    /// `a(n) = c(0) + c(1) n + ... + c(d) n^d`, the parameter is the coefficients
+    /// (constant coefficient first).
+    pub fn polynomial(coefficients: Vec<T>) -> Self {
+        Sequence::new().parameterized_transition_function(
+            coefficients,
+            |_, current_element_index, coefficients: &Vec<T>| {
+                let n = checked(
+                    current_element_index,
+                    T::from_integer(current_element_index as i128),
+                )?;
+                let mut element = T::zero();
+
+                for coefficient in coefficients.iter().rev() {
+                    element = checked(
+                        current_element_index,
+                        element
+                            .checked_mul(&n)
+                            .and_then(|product| product.checked_add(coefficient)),
+                    )?;
+                }
+
+                Ok(element)
+            },
+        )
+    }
+
+    /// Returns the k-bonacci sequence, which starts with k - 1 zeros and a one,
+    /// and whose next elements are the sum of the k preceding ones (k = 2 gives Fibonacci).
+    /// The parameter is k.
+    pub fn k_bonacci(k: usize) -> Self {
+        Sequence::new().parameterized_transition_function(
+            k,
+            |alive_elements, current_element_index, k: &usize| {
+                let k = *k;
+
+                if k == 0 {
+                    return Err(GenerationError::Failed {
+                        index: current_element_index,
+                        message: String::from("The k-bonacci sequence requires k > 0."),
+                    });
+                }
+
+                if current_element_index + 1 < k {
+                    return Ok(T::zero());
+                }
+
+                if current_element_index + 1 == k {
+                    return Ok(T::one());
+                }
+
+                let mut element = T::zero();
+
+                for index in current_element_index - k..current_element_index {
+                    element = checked(
+                        current_element_index,
+                        element.checked_add(alive_elements.try_nth_element(index)?),
+                    )?;
+                }
+
+                Ok(element)
+            },
+        )
+    }
+}
+
+/// Returns the element, or an error if the element is not representable.
+fn checked<T>(index: usize, element: Option<T>) -> GenerationResult<T> {
+    element.ok_or_else(|| GenerationError::Failed {
+        index,
+        message: String::from("The element is not representable."),
+    })
+}
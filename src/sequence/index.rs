@@ -0,0 +1,372 @@
+//! This module defines range indexes, auxiliary structures that are kept up to date
+//! as the elements of a sequence are generated and answer queries over ranges of indices
+//! (sums, counts, minimums and maximums) without reading the elements.
+//! The indexes are observers, they follow the truncation and the clearing of the sequence.
+
+use std::{
+    ops::Range,
+    sync::{Arc, Mutex, MutexGuard, PoisonError},
+};
+
+use super::{observer::SequenceObserver, states::WithTransitionFunction, Sequence};
+
+use crate::number::Number;
+
+/// Prefix sums of the elements, answers range sums in O(1).
+/// Clones of the index share the same sums so a clone can be kept
+/// to query the index after adding it to a sequence (also from another thread).
+#[derive(Debug, Clone)]
+pub struct PrefixSums<T> {
+    sums: Arc<Mutex<PrefixSumsState<T>>>,
+}
+
+/// The prefix sums, and whether the indexing stopped at an element
+/// whose prefix sum is not representable.
+#[derive(Debug)]
+struct PrefixSumsState<T> {
+    sums: Vec<T>,
+    overflowed: bool,
+}
+
+/// Fenwick tree of the elements that satisfy a predicate, answers range counts in O(log n).
+/// Clones of the index share the same tree.
+#[derive(Debug, Clone)]
+pub struct FenwickTree<T> {
+    tree: Arc<Mutex<Vec<usize>>>,
+    predicate: fn(&T) -> bool,
+}
+
+/// Sparse tables of the elements, answers range minimums and maximums in O(1).
+/// Clones of the index share the same tables.
+#[derive(Debug, Clone)]
+pub struct SparseTable<T> {
+    tables: Arc<Mutex<SparseTables<T>>>,
+}
+
+/// The minimums and maximums of the ranges of length 2^k (level k) starting at each index.
+#[derive(Debug)]
+struct SparseTables<T> {
+    min: Vec<Vec<T>>,
+    max: Vec<Vec<T>>,
+}
+
+impl<T, I> Sequence<T, I, WithTransitionFunction<T, I>> {
+    /// Adds a range index to the sequence, the alive elements are indexed first
+    /// (if no elements were dropped from storage) and the next elements as they are generated.
+    /// An index only covers the elements from the first one without gaps.
+    pub fn add_range_index(&mut self, mut index: impl SequenceObserver<T> + 'static) {
+        if self.dropped_elements.is_empty() {
+            for (position, element) in self.alive_elements.iter().enumerate() {
+                index.on_element(position, element);
+            }
+        }
+
+        self.add_observer(index);
+    }
+}
+
+impl<T: Number> PrefixSums<T> {
+    /// Creates an empty index.
+    pub fn new() -> Self {
+        Self {
+            sums: Arc::new(Mutex::new(PrefixSumsState {
+                sums: Vec::new(),
+                overflowed: false,
+            })),
+        }
+    }
+
+    /// Returns the number of indexed elements, the indexing stops at the first element
+    /// whose prefix sum is not representable (see `overflowed`).
+    pub fn len(&self) -> usize {
+        lock(&self.sums).sums.len()
+    }
+
+    /// Checks if the indexing stopped because the prefix sum of the element
+    /// that follows the indexed ones is not representable. The indexing resumes
+    /// when the sequence is truncated (or cleared) before that element.
+    pub fn overflowed(&self) -> bool {
+        lock(&self.sums).overflowed
+    }
+
+    /// Checks if the index is empty.
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Returns the sum of the elements of the indices, or None if they are not
+    /// all indexed or if the sum is not representable.
+    pub fn sum(&self, indices: Range<usize>) -> Option<T> {
+        let sums = &lock(&self.sums).sums;
+
+        if indices.start > indices.end || indices.end > sums.len() {
+            return None;
+        }
+
+        if indices.is_empty() {
+            return Some(T::zero());
+        }
+
+        match indices.start.checked_sub(1) {
+            Some(before) => sums[indices.end - 1].checked_sub(&sums[before]),
+            None => Some(sums[indices.end - 1].clone()),
+        }
+    }
+}
+
+impl<T: Number> Default for PrefixSums<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T: Number + Send> SequenceObserver<T> for PrefixSums<T> {
+    fn on_element(&mut self, index: usize, element: &T) {
+        let mut state = lock(&self.sums);
+
+        if index != state.sums.len() || state.overflowed {
+            return;
+        }
+
+        let sum = match state.sums.last() {
+            Some(last) => last.checked_add(element),
+            None => Some(element.clone()),
+        };
+
+        match sum {
+            Some(sum) => state.sums.push(sum),
+            None => state.overflowed = true,
+        }
+    }
+
+    fn on_clear(&mut self, retained: usize) {
+        self.on_truncate(retained);
+    }
+
+    fn on_truncate(&mut self, len: usize) {
+        let mut state = lock(&self.sums);
+
+        if len <= state.sums.len() {
+            state.sums.truncate(len);
+            state.overflowed = false;
+        }
+    }
+}
+
+impl<T> FenwickTree<T> {
+    /// Creates an empty index of the elements that satisfy the predicate.
+    pub fn new(predicate: fn(&T) -> bool) -> Self {
+        Self {
+            tree: Arc::new(Mutex::new(Vec::new())),
+            predicate,
+        }
+    }
+
+    /// Returns the number of indexed elements.
+    pub fn len(&self) -> usize {
+        lock(&self.tree).len()
+    }
+
+    /// Checks if the index is empty.
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Returns the number of elements of the indices that satisfy the predicate,
+    /// or None if they are not all indexed.
+    pub fn count(&self, indices: Range<usize>) -> Option<usize> {
+        let tree = lock(&self.tree);
+
+        if indices.start > indices.end || indices.end > tree.len() {
+            return None;
+        }
+
+        Some(prefix_count(&tree, indices.end) - prefix_count(&tree, indices.start))
+    }
+}
+
+/// Returns the number of the first `len` elements that satisfy the predicate,
+/// node `i` (1-based) of the tree counts the elements `i - lowest_bit(i)..i`.
+fn prefix_count(tree: &[usize], len: usize) -> usize {
+    let mut count = 0;
+    let mut node = len;
+
+    while node > 0 {
+        count += tree[node - 1];
+        node -= lowest_bit(node);
+    }
+
+    count
+}
+
+/// Returns the lowest set bit of the number.
+fn lowest_bit(number: usize) -> usize {
+    number & number.wrapping_neg()
+}
+
+impl<T> SequenceObserver<T> for FenwickTree<T> {
+    fn on_element(&mut self, index: usize, element: &T) {
+        let mut tree = lock(&self.tree);
+
+        if index != tree.len() {
+            return;
+        }
+
+        // The new node adds up the nodes of the elements it covers.
+        let node = index + 1;
+        let mut count = usize::from((self.predicate)(element));
+        let mut child = node - 1;
+
+        while child > node - lowest_bit(node) {
+            count += tree[child - 1];
+            child -= lowest_bit(child);
+        }
+
+        tree.push(count);
+    }
+
+    fn on_clear(&mut self, retained: usize) {
+        lock(&self.tree).truncate(retained);
+    }
+
+    fn on_truncate(&mut self, len: usize) {
+        lock(&self.tree).truncate(len);
+    }
+}
+
+impl<T: Clone + PartialOrd> SparseTable<T> {
+    /// Creates an empty index.
+    pub fn new() -> Self {
+        Self {
+            tables: Arc::new(Mutex::new(SparseTables {
+                min: Vec::new(),
+                max: Vec::new(),
+            })),
+        }
+    }
+
+    /// Returns the number of indexed elements.
+    pub fn len(&self) -> usize {
+        lock(&self.tables).len()
+    }
+
+    /// Checks if the index is empty.
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Returns the minimum of the elements of the indices,
+    /// or None if the indices are empty or not all indexed.
+    pub fn min(&self, indices: Range<usize>) -> Option<T> {
+        self.query(indices, |tables| &tables.min, min)
+    }
+
+    /// Returns the maximum of the elements of the indices,
+    /// or None if the indices are empty or not all indexed.
+    pub fn max(&self, indices: Range<usize>) -> Option<T> {
+        self.query(indices, |tables| &tables.max, max)
+    }
+
+    /// Combines the two ranges of length 2^k that cover the indices.
+    fn query(
+        &self,
+        indices: Range<usize>,
+        levels: fn(&SparseTables<T>) -> &Vec<Vec<T>>,
+        combine: fn(&T, &T) -> T,
+    ) -> Option<T> {
+        let tables = lock(&self.tables);
+
+        if indices.is_empty() || indices.end > tables.len() {
+            return None;
+        }
+
+        let level = indices.len().ilog2() as usize;
+        let values = &levels(&tables)[level];
+
+        Some(combine(
+            &values[indices.start],
+            &values[indices.end - (1 << level)],
+        ))
+    }
+}
+
+impl<T> SparseTables<T> {
+    /// Returns the number of indexed elements.
+    fn len(&self) -> usize {
+        self.min.first().map_or(0, Vec::len)
+    }
+}
+
+impl<T: Clone + PartialOrd> Default for SparseTable<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T: Clone + PartialOrd + Send> SequenceObserver<T> for SparseTable<T> {
+    fn on_element(&mut self, index: usize, element: &T) {
+        let mut tables = lock(&self.tables);
+
+        if index != tables.len() {
+            return;
+        }
+
+        push_level_values(&mut tables.min, element, min);
+        push_level_values(&mut tables.max, element, max);
+    }
+
+    fn on_clear(&mut self, retained: usize) {
+        self.on_truncate(retained);
+    }
+
+    fn on_truncate(&mut self, len: usize) {
+        let tables = &mut *lock(&self.tables);
+
+        for levels in [&mut tables.min, &mut tables.max] {
+            for (level, values) in levels.iter_mut().enumerate() {
+                values.truncate((len + 1).saturating_sub(1 << level));
+            }
+
+            levels.retain(|values| !values.is_empty());
+        }
+    }
+}
+
+/// Locks the shared state of an index, an index stays usable
+/// if a thread panicked while holding it.
+fn lock<S>(state: &Mutex<S>) -> MutexGuard<'_, S> {
+    state.lock().unwrap_or_else(PoisonError::into_inner)
+}
+
+/// Pushes the element and the ranges of each level that end with it.
+fn push_level_values<T: Clone>(levels: &mut Vec<Vec<T>>, element: &T, combine: fn(&T, &T) -> T) {
+    if levels.is_empty() {
+        levels.push(Vec::new());
+    }
+
+    levels[0].push(element.clone());
+    let len = levels[0].len();
+    let mut level = 1;
+
+    while 1 << level <= len {
+        if levels.len() == level {
+            levels.push(Vec::new());
+        }
+
+        let start = len - (1 << level);
+        let half = 1 << (level - 1);
+        let value = combine(&levels[level - 1][start], &levels[level - 1][start + half]);
+        levels[level].push(value);
+        level += 1;
+    }
+}
+
+/// Returns the smaller of the two values (the first one if they are not comparable).
+fn min<T: Clone + PartialOrd>(a: &T, b: &T) -> T {
+    if b < a { b } else { a }.clone()
+}
+
+/// Returns the larger of the two values (the first one if they are not comparable).
+fn max<T: Clone + PartialOrd>(a: &T, b: &T) -> T {
+    if b > a { b } else { a }.clone()
+}
@@ -0,0 +1,332 @@
+//! This module defines the ordered merge of monotone sequences (union, intersection
+//! and difference) and the smooth numbers, which are built on the same idea.
+//!
+//! The sources must be strictly increasing, the generation of an element fails
+//! if a source repeats a value (a source that repeats a value forever would never be
+//! skipped). The results are strictly increasing (the values shared by several sources
+//! appear once). An intersection or a difference that has no next element
+//! keeps searching forever, like any sequence whose transition does not terminate.
+
+use std::cell::RefCell;
+
+use super::{
+    error::GenerationError,
+    states::{WithInitialElements, WithTransitionFunction, WithoutInitialElements},
+    types::{DerivedSequence, GenerationResult},
+    Sequence,
+};
+
+use crate::sequence_part::types::AliveElementsPart;
+
+/// A source of elements of a merge, sources are `Send` so that merges are `Send`.
+trait MergeSource<T>: Send {
+    /// Returns a reference to the nth element of the source.
+    fn try_nth_element(&mut self, index: usize) -> GenerationResult<&T>;
+}
+
+impl<T: Send, I: Send> MergeSource<T> for Sequence<T, I, WithTransitionFunction<T, I>> {
+    fn try_nth_element(&mut self, index: usize) -> GenerationResult<&T> {
+        Sequence::try_nth_element(self, index)
+    }
+}
+
+/// Builder of the ordered merge of monotone sequences.
+pub struct MonotoneMerge<T> {
+    sources: Vec<Box<dyn MergeSource<T>>>,
+}
+
+/// The sources of a merge with the position of the next unused element of each source.
+struct Cursors<T> {
+    sources: Vec<Box<dyn MergeSource<T>>>,
+    positions: Vec<usize>,
+    next_index: usize,
+}
+
+impl<T: PartialOrd + Clone + Send + 'static> MonotoneMerge<T> {
+    /// Creates a merge without sources.
+    pub fn new() -> Self {
+        Self {
+            sources: Vec::new(),
+        }
+    }
+
+    /// Adds a strictly increasing sequence to the sources of the merge.
+    pub fn source<I: Send + 'static>(
+        mut self,
+        sequence: Sequence<T, I, WithTransitionFunction<T, I>>,
+    ) -> Self {
+        self.sources.push(Box::new(sequence));
+        self
+    }
+
+    /// Returns the sequence of the elements that are in any of the sources.
+    pub fn union(self) -> DerivedSequence<T> {
+        self.into_sequence(|cursors, current_element_index| {
+            let mut smallest: Option<T> = None;
+
+            for source in 0..cursors.sources.len() {
+                let head = cursors.head(source)?;
+
+                if smallest.as_ref().is_none_or(|smallest| head < smallest) {
+                    smallest = Some(head.clone());
+                }
+            }
+
+            let smallest = smallest.ok_or_else(|| no_sources(current_element_index))?;
+            cursors.skip_through(&smallest, current_element_index)?;
+
+            Ok(smallest)
+        })
+    }
+
+    /// Returns the sequence of the elements that are in all the sources.
+    pub fn intersection(self) -> DerivedSequence<T> {
+        self.into_sequence(|cursors, current_element_index| {
+            if cursors.sources.is_empty() {
+                return Err(no_sources(current_element_index));
+            }
+
+            loop {
+                let mut candidate = cursors.head(0)?.clone();
+
+                for source in 1..cursors.sources.len() {
+                    let head = cursors.head(source)?;
+
+                    if *head > candidate {
+                        candidate = head.clone();
+                    }
+                }
+
+                cursors.skip_before(&candidate, current_element_index)?;
+
+                let mut common = true;
+
+                for source in 0..cursors.sources.len() {
+                    common &= *cursors.head(source)? == candidate;
+                }
+
+                if common {
+                    cursors.skip_through(&candidate, current_element_index)?;
+                    return Ok(candidate);
+                }
+            }
+        })
+    }
+
+    /// Returns the sequence of the elements of the first source
+    /// that are not in any of the other sources.
+    pub fn difference(self) -> DerivedSequence<T> {
+        self.into_sequence(|cursors, current_element_index| {
+            if cursors.sources.is_empty() {
+                return Err(no_sources(current_element_index));
+            }
+
+            loop {
+                let candidate = cursors.head(0)?.clone();
+                cursors.skip_before(&candidate, current_element_index)?;
+
+                let mut excluded = false;
+
+                for source in 1..cursors.sources.len() {
+                    excluded |= *cursors.head(source)? == candidate;
+                }
+
+                cursors.skip_through(&candidate, current_element_index)?;
+
+                if !excluded {
+                    return Ok(candidate);
+                }
+            }
+        })
+    }
+
+    /// Creates the sequence whose elements are returned by the function, the cursors
+    /// are realigned with the sequence before each element (e.g. after a clear).
+    fn into_sequence(
+        self,
+        next_element: fn(&mut Cursors<T>, usize) -> GenerationResult<T>,
+    ) -> DerivedSequence<T> {
+        let sources_len = self.sources.len();
+        let cursors = RefCell::new(Cursors {
+            sources: self.sources,
+            positions: vec![0; sources_len],
+            next_index: 0,
+        });
+
+        Sequence::new().boxed_transition_function(
+            Box::new(move |alive_elements, current_element_index| {
+                let mut cursors = cursors.borrow_mut();
+                cursors.realign(&alive_elements, current_element_index)?;
+
+                // A failure can leave the cursors anywhere, so they are realigned on retry.
+                let element = next_element(&mut cursors, current_element_index);
+                cursors.next_index = match element {
+                    Ok(_) => current_element_index + 1,
+                    Err(_) => usize::MAX,
+                };
+
+                element
+            }),
+            None,
+        )
+    }
+}
+
+impl<T: PartialOrd + Clone + Send + 'static> Default for MonotoneMerge<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T: PartialOrd + Clone> Cursors<T> {
+    /// Returns the next unused element of the source.
+    fn head(&mut self, source: usize) -> GenerationResult<&T> {
+        self.sources[source].try_nth_element(self.positions[source])
+    }
+
+    /// Moves the cursors past the elements that are less than the value,
+    /// fails if a source repeats an element.
+    fn skip_before(&mut self, value: &T, current_element_index: usize) -> GenerationResult<()> {
+        for source in 0..self.sources.len() {
+            let mut skipped: Option<T> = None;
+
+            while self.head(source)? < value {
+                let head = self.head(source)?;
+
+                if skipped.as_ref() == Some(head) {
+                    return Err(repeated_element(source, current_element_index));
+                }
+
+                skipped = Some(head.clone());
+                self.positions[source] += 1;
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Moves the cursors past the elements that are less than or equal to the value,
+    /// fails if a source repeats an element.
+    fn skip_through(&mut self, value: &T, current_element_index: usize) -> GenerationResult<()> {
+        for source in 0..self.sources.len() {
+            let mut skipped: Option<T> = None;
+
+            while self.head(source)? <= value {
+                let head = self.head(source)?;
+
+                if skipped.as_ref() == Some(head) {
+                    return Err(repeated_element(source, current_element_index));
+                }
+
+                skipped = Some(head.clone());
+                self.positions[source] += 1;
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Moves the cursors (backward if needed) to the first elements that are greater
+    /// than the element that precedes the current one, this is only needed when
+    /// the elements are not generated right after the previous ones (e.g. after a clear).
+    fn realign(
+        &mut self,
+        alive_elements: &AliveElementsPart<'_, T, WithoutInitialElements>,
+        current_element_index: usize,
+    ) -> GenerationResult<()> {
+        if current_element_index == self.next_index {
+            return Ok(());
+        }
+
+        if current_element_index == 0 {
+            self.positions.fill(0);
+            return Ok(());
+        }
+
+        let previous = alive_elements.try_nth_element(current_element_index - 1)?;
+
+        for source in 0..self.sources.len() {
+            while self.positions[source] > 0
+                && self.sources[source].try_nth_element(self.positions[source] - 1)? > previous
+            {
+                self.positions[source] -= 1;
+            }
+        }
+
+        self.skip_through(previous, current_element_index)
+    }
+}
+
+/// Returns the error of a merge without sources.
+fn no_sources(index: usize) -> GenerationError {
+    GenerationError::Failed {
+        index,
+        message: String::from("The merge has no sources."),
+    }
+}
+
+/// Returns the error of a merge source that repeats an element.
+fn repeated_element(source: usize, index: usize) -> GenerationError {
+    GenerationError::Failed {
+        index,
+        message: format!(
+            "The source {source} of the merge repeats an element, \
+            the sources must be strictly increasing."
+        ),
+    }
+}
+
+impl Sequence<u64, WithInitialElements, WithTransitionFunction<u64, WithInitialElements>> {
+    /// Returns the sequence of the numbers whose prime factors are all in `primes`
+    /// (e.g. the Hamming numbers for 2, 3 and 5), starting from 1.
+    pub fn smooth_numbers(primes: Vec<u64>) -> Self {
+        Sequence::new()
+            .initial_elements(vec![1])
+            .boxed_transition_function(
+                Box::new(move |alive_elements, current_element_index| {
+                    let failed = |message: &str| GenerationError::Failed {
+                        index: current_element_index,
+                        message: String::from(message),
+                    };
+
+                    if primes.iter().all(|prime| *prime < 2) {
+                        return Err(failed("There are no primes greater than 1."));
+                    }
+
+                    let previous = *alive_elements.try_nth_element(current_element_index - 1)?;
+                    let mut smallest: Option<u64> = None;
+
+                    for prime in primes.iter().filter(|prime| **prime > 1) {
+                        // The first element whose multiple is greater than the previous element.
+                        let (mut low, mut high) = (0, current_element_index);
+
+                        while low < high {
+                            let middle = low + (high - low) / 2;
+
+                            if *alive_elements.try_nth_element(middle)? <= previous / prime {
+                                low = middle + 1;
+                            } else {
+                                high = middle;
+                            }
+                        }
+
+                        // Multiples that overflow can not be the next element.
+                        if let Some(multiple) =
+                            alive_elements.try_nth_element(low)?.checked_mul(*prime)
+                        {
+                            smallest =
+                                Some(smallest.map_or(multiple, |smallest| smallest.min(multiple)));
+                        }
+                    }
+
+                    smallest.ok_or_else(|| failed("The element overflows u64."))
+                }),
+                None,
+            )
+    }
+
+    /// Returns the sequence of the Hamming numbers (the 5-smooth numbers).
+    pub fn hamming_numbers() -> Self {
+        Self::smooth_numbers(vec![2, 3, 5])
+    }
+}
@@ -1,10 +1,40 @@
 //! This module defines the Sequence type
 //! and it is the core of this library
 
+pub mod budget;
+pub mod checkpoint;
+pub mod combinators;
+pub mod comparison;
+pub mod cursor;
+pub mod error;
+pub mod family;
+pub mod index;
+pub mod merge;
+pub mod observer;
+pub mod parallel;
 pub mod states;
+pub mod system;
+pub mod transforms;
 pub mod types;
 
-use self::{states::*, types::TransitionFunction};
+use std::{
+    any::Any,
+    collections::BTreeMap,
+    ops::Range,
+    panic::{self, AssertUnwindSafe},
+};
+
+use self::{
+    budget::{GenerationBudget, GenerationProgress},
+    checkpoint::Checkpoints,
+    error::GenerationError,
+    observer::SequenceObserver,
+    states::*,
+    types::{
+        BatchedTransitionFunction, BoxedTransitionFunction, FallibleTransitionFunction,
+        GenerationResult, RecurrenceFunction, TransitionFunction,
+    },
+};
 
 use crate::sequence_part::{
     error::RangeError,
@@ -22,8 +52,18 @@ pub struct Sequence<T, I, F> {
     trans_func: F,
     alive_elements: Vec<T>,
     iter_index: usize,
+    look_back: Option<usize>,
+    retained_elements: Option<usize>,
+    dropped_elements: Range<usize>,
+    observers: Vec<Box<dyn SequenceObserver<T>>>,
+    overrides: BTreeMap<usize, Override<T>>,
+    checkpoints: Option<Checkpoints<T>>,
 }
 
+/// An overridden element with the function that clones it
+/// (so that generation does not require the elements to be Clone).
+type Override<T> = (T, fn(&T) -> T);
+
 /// Shared behavior between sequences that requires initial elements
 /// and sequences that do not require initial elements.
 pub trait SharedSequenceBehavior {
@@ -32,6 +72,15 @@ pub trait SharedSequenceBehavior {
 
     /// Removes all alive (generated) elements.
     fn clear(&mut self);
+
+    /// Removes the alive elements from the index `len` onward, the initial elements
+    /// are always kept. The removed elements are generated again when they are requested.
+    fn truncate(&mut self, len: usize);
+
+    /// Drops the generated elements from storage except the last `number_of_elements` ones,
+    /// the initial elements are always kept. This is only safe for transitions that do not
+    /// read the dropped elements (see `bounded_storage`).
+    fn retain_last(&mut self, number_of_elements: usize);
 }
 
 impl<T> Default for Sequence<T, WithoutInitialElements, WithoutTransitionFunction> {
@@ -42,6 +91,12 @@ impl<T> Default for Sequence<T, WithoutInitialElements, WithoutTransitionFunctio
             trans_func: WithoutTransitionFunction,
             alive_elements: Vec::new(),
             iter_index: 0,
+            look_back: None,
+            retained_elements: None,
+            dropped_elements: 0..0,
+            observers: Vec::new(),
+            overrides: BTreeMap::new(),
+            checkpoints: None,
         }
     }
 }
@@ -57,11 +112,64 @@ impl<T> Sequence<T, WithoutInitialElements, WithoutTransitionFunction> {
         self,
         initial_elements: Vec<T>,
     ) -> Sequence<T, WithInitialElements, WithoutTransitionFunction> {
+        let initial_elements_len = initial_elements.len();
+        let mut sequence = self.map_states(|_, trans_func| {
+            (WithInitialElements::new(initial_elements_len), trans_func)
+        });
+        sequence.alive_elements = initial_elements;
+
+        sequence
+    }
+}
+
+impl<T: 'static> Sequence<T, WithoutInitialElements, WithoutTransitionFunction> {
+    /// Creates a sequence defined by a recurrence of order K,
+    /// the recurrence receives the last K elements as a window (oldest first).
+    /// The initial elements must hold at least K elements, this is checked at build time.
+    pub fn recurrence<const K: usize, const N: usize>(
+        self,
+        initial_elements: [T; N],
+        recurrence: RecurrenceFunction<T, K>,
+    ) -> Sequence<T, WithInitialElements, WithTransitionFunction<T, WithInitialElements>> {
+        const {
+            assert!(
+                N >= K,
+                "A recurrence of order K requires at least K initial elements."
+            )
+        };
+
+        self.initial_elements(Vec::from(initial_elements))
+            .boxed_transition_function(
+                Box::new(move |alive_elements, current_element_index| {
+                    let window = std::array::from_fn(|offset| {
+                        alive_elements
+                            .nth_element(current_element_index - K + offset)
+                            .expect("Elements in the recurrence window are always alive.")
+                    });
+
+                    Ok(recurrence(window, current_element_index))
+                }),
+                Some(K),
+            )
+    }
+}
+
+impl<T, I, F> Sequence<T, I, F> {
+    /// Moves the sequence into the states returned by the function, the other fields are kept.
+    fn map_states<J, G>(self, states: impl FnOnce(I, F) -> (J, G)) -> Sequence<T, J, G> {
+        let (initial_elements, trans_func) = states(self.initial_elements, self.trans_func);
+
         Sequence {
-            initial_elements: WithInitialElements::new(initial_elements.len()),
-            trans_func: self.trans_func,
-            alive_elements: initial_elements,
+            initial_elements,
+            trans_func,
+            alive_elements: self.alive_elements,
             iter_index: self.iter_index,
+            look_back: self.look_back,
+            retained_elements: self.retained_elements,
+            dropped_elements: self.dropped_elements,
+            observers: self.observers,
+            overrides: self.overrides,
+            checkpoints: self.checkpoints,
         }
     }
 }
@@ -79,32 +187,306 @@ impl<T, I> Sequence<T, I, WithoutTransitionFunction> {
         self,
         trans_func: TransitionFunction<T, I>,
     ) -> Sequence<T, I, WithTransitionFunction<T, I>> {
-        Sequence {
-            initial_elements: self.initial_elements,
-            trans_func: WithTransitionFunction::new(trans_func),
-            alive_elements: self.alive_elements,
-            iter_index: self.iter_index,
-        }
+        self.map_states(|initial_elements, _| {
+            (initial_elements, WithTransitionFunction::new(trans_func))
+        })
+    }
+
+    /// Adds a boxed transition to the sequence, with the number
+    /// of preceding elements it reads (if it is known).
+    pub(crate) fn boxed_transition_function(
+        self,
+        trans_func: BoxedTransitionFunction<T, I>,
+        look_back: Option<usize>,
+    ) -> Sequence<T, I, WithTransitionFunction<T, I>> {
+        let mut sequence = self.map_states(|initial_elements, _| {
+            (
+                initial_elements,
+                WithTransitionFunction::new_boxed(trans_func),
+            )
+        });
+        sequence.look_back = look_back;
+
+        sequence
+    }
+
+    /// Adds a transition function that generates blocks of elements to the sequence,
+    /// the blocks are aligned to multiples of the block length. A block can be cut short
+    /// (e.g. after the initial elements or before an overridden element), and it is generated
+    /// entirely even if only its first elements were requested.
+    pub fn batched_transition_function(
+        self,
+        block_len: usize,
+        trans_func: BatchedTransitionFunction<T, I>,
+    ) -> Sequence<T, I, WithTransitionFunction<T, I>> {
+        self.map_states(|initial_elements, _| {
+            (
+                initial_elements,
+                WithTransitionFunction::new_batched(block_len, trans_func),
+            )
+        })
+    }
+
+    /// Adds a transition function that can fail to the sequence.
+    pub fn fallible_transition_function(
+        self,
+        trans_func: FallibleTransitionFunction<T, I>,
+    ) -> Sequence<T, I, WithTransitionFunction<T, I>> {
+        self.map_states(|initial_elements, _| {
+            (
+                initial_elements,
+                WithTransitionFunction::new_fallible(trans_func),
+            )
+        })
     }
 }
 
 impl<T, I> Sequence<T, I, WithTransitionFunction<T, I>> {
     /// Generates the nth element and all the preceding elements and stores them in the sequence.
+    /// Panics with the description of the error if the generation fails.
     fn generate_nth_element(&mut self, nth_element: usize) {
-        if !self.nth_element_is_alive(nth_element) {
-            let alive_elements_len = self.alive_elements_len();
+        self.try_generate_nth_element(nth_element)
+            .unwrap_or_else(|error| panic!("{error}"));
+    }
+
+    /// Generates the nth element and all the preceding elements and stores them in the sequence.
+    /// The elements generated before a failure stay alive.
+    fn try_generate_nth_element(&mut self, nth_element: usize) -> GenerationResult<()> {
+        self.generate_nth_element_with(nth_element, None, false)
+            .map(|_| ())
+    }
+
+    /// Generates the nth element and all the preceding elements and stores them in the sequence,
+    /// the generation stops early when the budget (if any) is exhausted, and the panics
+    /// of the transition function are caught and returned as errors if `catch_panics` is set.
+    fn generate_nth_element_with(
+        &mut self,
+        nth_element: usize,
+        budget: Option<&GenerationBudget>,
+        catch_panics: bool,
+    ) -> GenerationResult<GenerationProgress> {
+        let indices = self.next_index()..nth_element + 1;
+
+        if self.nth_element_is_alive(nth_element) || indices.is_empty() {
+            return Ok(GenerationProgress::new(0, self.next_index(), None));
+        }
+
+        #[cfg(feature = "tracing")]
+        let _span =
+            tracing::debug_span!("generate", start = indices.start, end = indices.end).entered();
+
+        for observer in &mut self.observers {
+            observer.on_batch_start(indices.clone());
+        }
+
+        let mut generated = 0;
+        let progress = self.generate_elements(indices, budget, catch_panics, &mut generated);
+
+        for observer in &mut self.observers {
+            observer.on_batch_end(generated);
+        }
+
+        progress
+    }
 
-            for current_element_index in alive_elements_len..=nth_element {
+    /// Generates the elements of the indices in order, `generated` counts the generated elements.
+    /// Batched transitions can generate elements past the indices to complete their block,
+    /// the blocks go through a buffer while single elements are pushed directly.
+    fn generate_elements(
+        &mut self,
+        indices: Range<usize>,
+        budget: Option<&GenerationBudget>,
+        catch_panics: bool,
+        generated: &mut usize,
+    ) -> GenerationResult<GenerationProgress> {
+        if budget.is_none() && !catch_panics && self.overrides.is_empty() {
+            if let Some(progress) = self.generate_single_elements(indices.clone(), generated) {
+                return progress;
+            }
+        }
+
+        let mut buffer = Vec::new();
+        let mut current_element_index = indices.start;
+        // The overrides do not change during the generation, the lookups are skipped without any.
+        let has_overrides = !self.overrides.is_empty();
+
+        while current_element_index < indices.end {
+            if let Some(stop) = budget.and_then(|budget| budget.exhausted(*generated)) {
+                return Ok(GenerationProgress::new(
+                    *generated,
+                    current_element_index,
+                    Some(stop),
+                ));
+            }
+
+            let overridden = if has_overrides {
+                self.overrides.get(&current_element_index)
+            } else {
+                None
+            };
+
+            let new_element = if let Some((element, clone)) = overridden {
+                Some(clone(element))
+            } else {
+                // Blocks end before the next overridden element, and at the step limit.
+                let next_override = if has_overrides {
+                    self.overrides.range(current_element_index + 1..).next()
+                } else {
+                    None
+                };
+                let end = next_override
+                    .map_or(usize::MAX, |(index, _)| *index)
+                    .min(budget.map_or(usize::MAX, |budget| {
+                        current_element_index.saturating_add(budget.remaining_steps(*generated))
+                    }));
                 let alive_elements_part = self.alive_elements();
-                let new_element = self
-                    .trans_func
-                    .run(alive_elements_part, current_element_index);
 
+                if catch_panics {
+                    panic::catch_unwind(AssertUnwindSafe(|| {
+                        self.trans_func.run(
+                            alive_elements_part,
+                            current_element_index,
+                            end,
+                            &mut buffer,
+                        )
+                    }))
+                    .map_err(|payload| {
+                        GenerationError::TransitionPanicked {
+                            index: current_element_index,
+                            message: panic_message(payload),
+                        }
+                    })??
+                } else {
+                    self.trans_func.run(
+                        alive_elements_part,
+                        current_element_index,
+                        end,
+                        &mut buffer,
+                    )?
+                }
+            };
+
+            if let Some(new_element) = new_element {
+                self.push_element(current_element_index, new_element);
+                current_element_index += 1;
+                *generated += 1;
+                continue;
+            }
+
+            for new_element in buffer.drain(..) {
+                self.push_element(current_element_index, new_element);
+                current_element_index += 1;
+                *generated += 1;
+            }
+        }
+
+        Ok(GenerationProgress::new(*generated, self.next_index(), None))
+    }
+
+    /// Generates the elements of the indices without a budget, overrides or blocks,
+    /// or returns None if the transition function generates blocks.
+    fn generate_single_elements(
+        &mut self,
+        indices: Range<usize>,
+        generated: &mut usize,
+    ) -> Option<GenerationResult<GenerationProgress>> {
+        if self.trans_func.generates_blocks() {
+            return None;
+        }
+
+        // Without observers, checkpoints or bounded storage the elements are only stored.
+        let only_store = self.observers.is_empty()
+            && self.checkpoints.is_none()
+            && self.retained_elements.is_none();
+
+        for current_element_index in indices {
+            let new_element = match self
+                .trans_func
+                .element(self.alive_elements(), current_element_index)
+            {
+                Ok(new_element) => new_element,
+                Err(error) => return Some(Err(error)),
+            };
+
+            if only_store {
                 self.alive_elements.push(new_element);
+            } else {
+                self.push_element(current_element_index, new_element);
+            }
+
+            *generated += 1;
+        }
+
+        Some(Ok(GenerationProgress::new(
+            *generated,
+            self.next_index(),
+            None,
+        )))
+    }
+
+    /// Stores the generated element of the index, notifies the observers
+    /// and records a checkpoint if one is due.
+    #[inline]
+    fn push_element(&mut self, index: usize, element: T) {
+        self.alive_elements.push(element);
+
+        // The common case without observers, checkpoints or bounded storage stays cheap.
+        if !self.observers.is_empty() {
+            let element = &self.alive_elements[self.alive_elements.len() - 1];
+
+            for observer in &mut self.observers {
+                observer.on_element(index, element);
+            }
+        }
+
+        if self.checkpoints.is_some() {
+            self.record_checkpoint(index + 1);
+        }
+
+        if self.retained_elements.is_some() {
+            self.drop_elements_out_of_window();
+        }
+    }
+
+    /// Drops the elements that are older than the retained window (if any),
+    /// the elements are dropped in batches so that dropping stays cheap.
+    fn drop_elements_out_of_window(&mut self) {
+        if let Some(retained_elements) = self.retained_elements {
+            let retained_elements = retained_elements.max(1);
+            let window_start = self.dropped_elements.start;
+            let window_len = self.alive_elements_len() - window_start;
+
+            if window_len >= 2 * retained_elements {
+                let dropped = window_len - retained_elements;
+                self.alive_elements
+                    .drain(window_start..window_start + dropped);
+                self.dropped_elements.end += dropped;
             }
         }
     }
 
+    /// Returns the position of the nth element in the storage of the alive elements,
+    /// or None if the element was dropped.
+    fn storage_position(&self, index: usize) -> Option<usize> {
+        if index < self.dropped_elements.start {
+            Some(index)
+        } else if index < self.dropped_elements.end {
+            None
+        } else {
+            Some(index - self.dropped_elements.len())
+        }
+    }
+
+    /// Returns the index of the next element to be generated.
+    pub(crate) fn next_index(&self) -> usize {
+        self.alive_elements_len() + self.dropped_elements.len()
+    }
+
+    /// Returns a reference to the alive element stored at the given position.
+    pub(crate) fn alive_element_at(&self, position: usize) -> Option<&T> {
+        self.alive_elements.get(position)
+    }
+
     /// Returns a reference to the nth element if it is alive in a Some variant
     /// This method does not generate the nth elements if it is dead it just returns None.
     pub(crate) fn nth_element_without_generation(&self, index: usize) -> Option<&T> {
@@ -112,12 +494,88 @@ impl<T, I> Sequence<T, I, WithTransitionFunction<T, I>> {
             return None;
         }
 
-        Some(&self.alive_elements[index])
+        self.storage_position(index)
+            .map(|position| &self.alive_elements[position])
     }
 
     /// Generates the specified number of elements.
     pub fn generate(&mut self, number_of_elements: usize) {
-        self.generate_nth_element(self.alive_elements_len() + number_of_elements - 1);
+        self.generate_nth_element(self.next_index() + number_of_elements - 1);
+    }
+
+    /// Generates the specified number of elements,
+    /// returns the error of the transition function if it fails.
+    pub fn try_generate(&mut self, number_of_elements: usize) -> GenerationResult<()> {
+        if number_of_elements == 0 {
+            return Ok(());
+        }
+
+        self.try_generate_nth_element(self.next_index() + number_of_elements - 1)
+    }
+
+    /// Generates the specified number of elements, catching the panics of the transition function.
+    /// On failure the error records the index of the failing element, all the elements
+    /// before it stay alive and the failing element and the ones after it stay dead,
+    /// so the generation can be resumed (e.g. after replacing the transition function).
+    pub fn generate_checked(&mut self, number_of_elements: usize) -> GenerationResult<()> {
+        if number_of_elements == 0 {
+            return Ok(());
+        }
+
+        self.generate_nth_element_with(self.next_index() + number_of_elements - 1, None, true)
+            .map(|_| ())
+    }
+
+    /// Generates the specified number of elements within the limits of the budget.
+    /// When a limit is hit the generation stops and the returned progress tells
+    /// how far it got, the generated elements stay alive.
+    pub fn generate_with_budget(
+        &mut self,
+        number_of_elements: usize,
+        budget: &GenerationBudget,
+    ) -> GenerationResult<GenerationProgress> {
+        if number_of_elements == 0 {
+            return Ok(GenerationProgress::new(0, self.next_index(), None));
+        }
+
+        self.generate_nth_element_with_budget(self.next_index() + number_of_elements - 1, budget)
+    }
+
+    /// Generates the nth element and all the preceding elements within the limits of the budget.
+    /// When a limit is hit the generation stops and the returned progress tells
+    /// how far it got, the generated elements stay alive.
+    pub fn generate_nth_element_with_budget(
+        &mut self,
+        nth_element: usize,
+        budget: &GenerationBudget,
+    ) -> GenerationResult<GenerationProgress> {
+        self.generate_nth_element_with(nth_element, Some(budget), false)
+    }
+
+    /// Replaces the transition function of the sequence,
+    /// the alive elements are kept and the next generated elements use the new transition function.
+    /// The segments of `replace_transition_function_from` are discarded, and since the look-back
+    /// of the new transition function is unknown, the look-back is reset: no more elements
+    /// are dropped from storage (see `bounded_storage`) and no more checkpoints are recorded.
+    pub fn replace_transition_function(&mut self, trans_func: TransitionFunction<T, I>) {
+        self.replace_whole_transition(WithTransitionFunction::new(trans_func));
+    }
+
+    /// Replaces the transition function of the sequence with a transition function that can fail,
+    /// see `replace_transition_function`.
+    pub fn replace_fallible_transition_function(
+        &mut self,
+        trans_func: FallibleTransitionFunction<T, I>,
+    ) {
+        self.replace_whole_transition(WithTransitionFunction::new_fallible(trans_func));
+    }
+
+    /// Replaces the whole transition and resets the look-back.
+    fn replace_whole_transition(&mut self, trans_func: WithTransitionFunction<T, I>) {
+        self.trans_func = trans_func;
+        self.look_back = None;
+        self.retained_elements = None;
+        self.checkpoints = None;
     }
 
     /// Returns the length of the alive elements.
@@ -127,14 +585,37 @@ impl<T, I> Sequence<T, I, WithTransitionFunction<T, I>> {
 
     /// Checks if an element is alive.
     pub fn nth_element_is_alive(&self, index: usize) -> bool {
-        index < self.alive_elements_len()
+        index < self.next_index() && !self.dropped_elements.contains(&index)
     }
 
     /// Returns a reference to the nth element if it is alive.
     /// This method generate the nth element if it is dead before returning its reference.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the generation fails or if the element was dropped
+    /// from storage (see `bounded_storage`).
     pub fn nth_element(&mut self, index: usize) -> &T {
-        self.generate_nth_element(index);
-        &self.alive_elements[index]
+        self.try_nth_element(index)
+            .unwrap_or_else(|error| panic!("{error}"))
+    }
+
+    /// Returns a reference to the nth element,
+    /// or the error that prevented it from being generated.
+    pub fn try_nth_element(&mut self, index: usize) -> GenerationResult<&T> {
+        self.try_generate_nth_element(index)?;
+
+        let position = self
+            .storage_position(index)
+            .ok_or(GenerationError::DroppedElement { index })?;
+
+        Ok(&self.alive_elements[position])
+    }
+
+    /// Returns the number of preceding elements the transition reads,
+    /// if it was declared (e.g. by building the sequence with `recurrence`).
+    pub fn look_back(&self) -> Option<usize> {
+        self.look_back
     }
 
     /// Returns a sequence part that represents the alive elements.
@@ -161,16 +642,258 @@ impl<T, I> Sequence<T, I, WithTransitionFunction<T, I>> {
             return Err(RangeError::InvalidRange { start, end });
         }
 
+        for observer in &mut self.observers {
+            observer.on_range(start, end);
+        }
+
         Ok(RangePartMut::new_range_mut(self, start, end))
     }
+
+    /// Shrinks the capacity of the storage of the alive elements as much as possible.
+    pub fn shrink_to_fit(&mut self) {
+        self.alive_elements.shrink_to_fit();
+    }
+
+    /// Reserves capacity for at least `additional` more alive elements.
+    pub fn reserve(&mut self, additional: usize) {
+        self.alive_elements.reserve(additional);
+    }
+
+    /// Removes the alive elements from the index `len` onward, the elements before
+    /// `initial_elements_len` are always kept. If an element that the next generated
+    /// elements may read was dropped, the sequence is truncated to its initial elements.
+    fn truncate_alive_elements(&mut self, len: usize, initial_elements_len: usize) {
+        let mut len = len.max(initial_elements_len);
+
+        if len >= self.next_index() {
+            return;
+        }
+
+        // Without a declared look-back, the next elements may read any dropped element.
+        let reads_dropped_element = self
+            .look_back
+            .is_none_or(|window_len| len < self.dropped_elements.end + window_len);
+        if !self.dropped_elements.is_empty() && reads_dropped_element {
+            len = self.dropped_elements.start;
+            self.dropped_elements = len..len;
+        }
+
+        let position = self
+            .storage_position(len)
+            .expect("The truncation index is never a dropped element.");
+        self.alive_elements.truncate(position);
+
+        if let Some(checkpoints) = &mut self.checkpoints {
+            checkpoints.truncate(len);
+        }
+
+        for observer in &mut self.observers {
+            observer.on_truncate(len);
+        }
+    }
+
+    /// Drops the generated elements except the last `number_of_elements` ones,
+    /// the elements before `initial_elements_len` are always kept.
+    fn retain_last_alive_elements(
+        &mut self,
+        number_of_elements: usize,
+        initial_elements_len: usize,
+    ) {
+        if self.dropped_elements.is_empty() {
+            self.dropped_elements = initial_elements_len..initial_elements_len;
+        }
+
+        let window_start = self.dropped_elements.start;
+        let window_len = self.alive_elements_len() - window_start;
+
+        if window_len > number_of_elements {
+            let dropped = window_len - number_of_elements;
+            self.alive_elements
+                .drain(window_start..window_start + dropped);
+            self.dropped_elements.end += dropped;
+        }
+
+        // The iteration continues with the first element that is still alive.
+        if self.dropped_elements.contains(&self.iter_index) {
+            self.iter_index = self.dropped_elements.end;
+        }
+    }
+
+    /// Adds an observer to the sequence.
+    pub fn add_observer(&mut self, observer: impl SequenceObserver<T> + 'static) {
+        self.observers.push(Box::new(observer));
+    }
+
+    /// Returns the overridden elements ordered by index.
+    pub fn overrides(&self) -> impl Iterator<Item = (usize, &T)> {
+        self.overrides
+            .iter()
+            .map(|(index, (element, _))| (*index, element))
+    }
+}
+
+impl<T, I: InitialElementsState> Sequence<T, I, WithTransitionFunction<T, I>> {
+    /// Replaces the transition function of the sequence from the index onward,
+    /// the elements before the index keep being generated by the previous transition functions.
+    /// With `KeepAlive` the alive elements from the index onward are kept
+    /// (they are generated with the new transition function only if they are removed).
+    /// The look-back of the new transition function is unknown, so the look-back is reset
+    /// as in `replace_transition_function`.
+    pub fn replace_transition_function_from(
+        &mut self,
+        index: usize,
+        trans_func: TransitionFunction<T, I>,
+        mode: ReplacementMode,
+    ) {
+        self.replace_from(index, WithTransitionFunction::new(trans_func), None, mode);
+    }
+
+    /// Replaces the transition function of the sequence from the index onward
+    /// with a transition function that can fail, see `replace_transition_function_from`.
+    pub fn replace_fallible_transition_function_from(
+        &mut self,
+        index: usize,
+        trans_func: FallibleTransitionFunction<T, I>,
+        mode: ReplacementMode,
+    ) {
+        self.replace_from(
+            index,
+            WithTransitionFunction::new_fallible(trans_func),
+            None,
+            mode,
+        );
+    }
+
+    /// Replaces the transition of the sequence from the index onward with a boxed transition
+    /// that reads the `look_back` preceding elements. With `KeepAlive`, the replacement is
+    /// rejected if the first element it generates reads an element that was dropped from storage.
+    pub(crate) fn replace_boxed_transition_function_from(
+        &mut self,
+        index: usize,
+        trans_func: BoxedTransitionFunction<T, I>,
+        look_back: usize,
+        mode: ReplacementMode,
+    ) -> GenerationResult<()> {
+        let first_index = index.max(self.next_index());
+        let window_start = first_index.saturating_sub(look_back);
+
+        if mode == ReplacementMode::KeepAlive
+            && !self.dropped_elements.is_empty()
+            && window_start < self.dropped_elements.end
+        {
+            return Err(GenerationError::DroppedElement {
+                index: window_start.max(self.dropped_elements.start),
+            });
+        }
+
+        self.replace_from(
+            index,
+            WithTransitionFunction::new_boxed(trans_func),
+            Some(look_back),
+            mode,
+        );
+
+        Ok(())
+    }
+
+    /// Replaces the transition from the index onward, the look-back of the sequence
+    /// grows to the look-back of the new transition (or is reset if it is unknown),
+    /// and the checkpoints are no longer recorded if it changes.
+    fn replace_from(
+        &mut self,
+        index: usize,
+        trans_func: WithTransitionFunction<T, I>,
+        look_back: Option<usize>,
+        mode: ReplacementMode,
+    ) {
+        self.trans_func.replace_from(index, trans_func);
+
+        let previous_look_back = self.look_back;
+        self.look_back = previous_look_back
+            .zip(look_back)
+            .map(|(previous, look_back)| previous.max(look_back));
+
+        if self.look_back != previous_look_back {
+            self.retained_elements = self.retained_elements.and(self.look_back);
+            self.checkpoints = None;
+        }
+
+        if mode == ReplacementMode::Invalidate {
+            self.truncate_alive_elements(index, self.initial_elements.initial_elements_len());
+        }
+    }
+
+    /// Returns the indices from which the replaced transition functions apply (in order),
+    /// `replace_transition_function` removes them all.
+    pub fn transition_function_segments(&self) -> impl Iterator<Item = usize> + '_ {
+        self.trans_func.segment_starts()
+    }
+
+    /// Overrides the nth element with the value, the alive elements from the index onward
+    /// are removed so that the next elements are generated from the value.
+    /// The override is kept by `clear`, `truncate`, etc... until it is removed.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the index is an initial element.
+    pub fn set_override(&mut self, index: usize, element: T)
+    where
+        T: Clone,
+    {
+        let initial_elements_len = self.initial_elements.initial_elements_len();
+        assert!(
+            index >= initial_elements_len,
+            "Initial elements can not be overridden."
+        );
+
+        self.overrides.insert(index, (element, T::clone));
+        self.truncate_alive_elements(index, initial_elements_len);
+    }
+
+    /// Removes the override of the nth element and returns the overriding value,
+    /// the alive elements from the index onward are removed so that they are generated again.
+    pub fn remove_override(&mut self, index: usize) -> Option<T> {
+        let (element, _) = self.overrides.remove(&index)?;
+        self.truncate_alive_elements(index, self.initial_elements.initial_elements_len());
+
+        Some(element)
+    }
+
+    /// Removes all the overrides, the alive elements from the first overridden index
+    /// onward are removed so that they are generated again.
+    pub fn clear_overrides(&mut self) {
+        if let Some(first_index) = self.overrides.keys().next().copied() {
+            self.overrides.clear();
+            self.truncate_alive_elements(first_index, self.initial_elements.initial_elements_len());
+        }
+    }
 }
 
 impl
     Sequence<usize, WithoutInitialElements, WithTransitionFunction<usize, WithoutInitialElements>>
 {
-    /// Returns a linear sequence.
+    /// Returns a linear sequence, its elements can be generated in parallel.
     pub fn linear_seq() -> Self {
-        Sequence::new().transition_function(|_, i| i)
+        Sequence::new().index_function(|i| i)
+    }
+}
+
+impl<T, I: InitialElementsState> Sequence<T, I, WithTransitionFunction<T, I>> {
+    /// Makes the sequence keep only the initial elements and the elements its
+    /// recurrence looks back at, older elements are dropped from storage.
+    /// This has no effect on sequences that do not declare a look-back.
+    pub fn bounded_storage(mut self) -> Self {
+        if let Some(look_back) = self.look_back {
+            if self.dropped_elements.is_empty() {
+                let initial_elements_len = self.initial_elements.initial_elements_len();
+                self.dropped_elements = initial_elements_len..initial_elements_len;
+            }
+
+            self.retained_elements = Some(look_back);
+            self.drop_elements_out_of_window();
+        }
+
+        self
     }
 }
 
@@ -188,13 +911,25 @@ impl<T> SharedSequenceBehavior
     }
 
     fn clear(&mut self) {
-        let mut initial_elements = Vec::new();
+        let initial_elements_len = self.initial_elements_len();
+        self.alive_elements.truncate(initial_elements_len);
+        self.dropped_elements = self.dropped_elements.start..self.dropped_elements.start;
+
+        if let Some(checkpoints) = &mut self.checkpoints {
+            checkpoints.truncate(initial_elements_len);
+        }
 
-        for index in 0..self.initial_elements_len() {
-            initial_elements.push(self.alive_elements.remove(index));
+        for observer in &mut self.observers {
+            observer.on_clear(self.alive_elements.len());
         }
+    }
+
+    fn truncate(&mut self, len: usize) {
+        self.truncate_alive_elements(len, self.initial_elements_len());
+    }
 
-        self.alive_elements = initial_elements;
+    fn retain_last(&mut self, number_of_elements: usize) {
+        self.retain_last_alive_elements(number_of_elements, self.initial_elements_len());
     }
 }
 
@@ -211,6 +946,23 @@ impl<T> SharedSequenceBehavior
 
     fn clear(&mut self) {
         self.alive_elements = Vec::new();
+        self.dropped_elements = 0..0;
+
+        if let Some(checkpoints) = &mut self.checkpoints {
+            checkpoints.truncate(0);
+        }
+
+        for observer in &mut self.observers {
+            observer.on_clear(0);
+        }
+    }
+
+    fn truncate(&mut self, len: usize) {
+        self.truncate_alive_elements(len, 0);
+    }
+
+    fn retain_last(&mut self, number_of_elements: usize) {
+        self.retain_last_alive_elements(number_of_elements, 0);
     }
 }
 
@@ -220,7 +972,7 @@ impl<T: Clone, I> Iterator for Sequence<T, I, WithTransitionFunction<T, I>> {
     fn next(&mut self) -> Option<Self::Item> {
         let iter_index = self.iter_index;
 
-        if iter_index == std::usize::MAX {
+        if iter_index == usize::MAX {
             self.iter_index = 0;
             return None;
         }
@@ -230,3 +982,14 @@ impl<T: Clone, I> Iterator for Sequence<T, I, WithTransitionFunction<T, I>> {
         Some(self.nth_element(iter_index).clone())
     }
 }
+
+/// Returns the message of a panic payload.
+fn panic_message(payload: Box<dyn Any + Send>) -> String {
+    if let Some(message) = payload.downcast_ref::<&str>() {
+        message.to_string()
+    } else if let Some(message) = payload.downcast_ref::<String>() {
+        message.clone()
+    } else {
+        String::from("Unknown panic payload.")
+    }
+}
@@ -0,0 +1,120 @@
+//! This module defines the observers of the Sequence type,
+//! observers are notified about what happens on the sequence
+//! (e.g. to report progress or collect metrics)
+
+use std::{
+    ops::Range,
+    sync::{Arc, Mutex, MutexGuard, PoisonError},
+    time::{Duration, Instant},
+};
+
+/// An observer of a sequence, observers are `Send` so that
+/// sequences with observers can be moved to other threads.
+/// All the callbacks do nothing by default.
+pub trait SequenceObserver<T>: Send {
+    /// Called at the start of a generation call with the indices
+    /// of the elements that are about to be generated.
+    fn on_batch_start(&mut self, _indices: Range<usize>) {}
+
+    /// Called after each generated element.
+    fn on_element(&mut self, _index: usize, _element: &T) {}
+
+    /// Called at the end of a generation call (also when it stops early or fails)
+    /// with the number of elements that were generated.
+    fn on_batch_end(&mut self, _generated: usize) {}
+
+    /// Called after the alive elements were cleared,
+    /// with the number of the elements that were kept (initial elements).
+    fn on_clear(&mut self, _retained: usize) {}
+
+    /// Called after the alive elements were truncated,
+    /// with the index of the first element that was removed.
+    fn on_truncate(&mut self, _len: usize) {}
+
+    /// Called when a mutable range of the sequence is created
+    /// (immutable ranges only read the alive elements and are not reported).
+    fn on_range(&mut self, _start: usize, _end: usize) {}
+}
+
+/// Throughput statistics of the generation of a sequence.
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct ThroughputStats {
+    elements: usize,
+    batches: usize,
+    total_time: Duration,
+}
+
+impl ThroughputStats {
+    /// Returns the number of generated elements.
+    pub fn elements(&self) -> usize {
+        self.elements
+    }
+
+    /// Returns the number of generation calls.
+    pub fn batches(&self) -> usize {
+        self.batches
+    }
+
+    /// Returns the total time spent generating elements.
+    pub fn total_time(&self) -> Duration {
+        self.total_time
+    }
+
+    /// Returns the number of generated elements per second.
+    pub fn elements_per_sec(&self) -> f64 {
+        let total_secs = self.total_time.as_secs_f64();
+
+        if total_secs == 0.0 {
+            return 0.0;
+        }
+
+        self.elements as f64 / total_secs
+    }
+}
+
+/// An observer that collects throughput statistics,
+/// clones of the observer share the same statistics so a clone
+/// can be kept to read the statistics after adding the observer to a sequence.
+#[derive(Debug, Clone, Default)]
+pub struct ThroughputObserver {
+    stats: Arc<Mutex<ThroughputStats>>,
+    batch_start: Option<Instant>,
+}
+
+impl ThroughputObserver {
+    /// Creates a new observer with empty statistics.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns the collected statistics.
+    pub fn stats(&self) -> ThroughputStats {
+        *self.lock_stats()
+    }
+
+    /// Resets the collected statistics.
+    pub fn reset(&self) {
+        *self.lock_stats() = ThroughputStats::default();
+    }
+
+    /// Locks the shared statistics, they stay usable
+    /// if a thread panicked while holding them.
+    fn lock_stats(&self) -> MutexGuard<'_, ThroughputStats> {
+        self.stats.lock().unwrap_or_else(PoisonError::into_inner)
+    }
+}
+
+impl<T> SequenceObserver<T> for ThroughputObserver {
+    fn on_batch_start(&mut self, _indices: Range<usize>) {
+        self.batch_start = Some(Instant::now());
+    }
+
+    fn on_batch_end(&mut self, generated: usize) {
+        if let Some(batch_start) = self.batch_start.take() {
+            let mut stats = self.lock_stats();
+            stats.elements += generated;
+            stats.batches += 1;
+            stats.total_time += batch_start.elapsed();
+        }
+    }
+}
@@ -0,0 +1,143 @@
+//! This module defines the parallel generation of sequences whose elements
+//! are functions of the index, the elements are generated in chunks across threads
+//! (or with rayon if the `rayon` feature is enabled) and stored in order.
+
+use std::ops::Range;
+
+use super::{
+    states::{WithTransitionFunction, WithoutInitialElements, WithoutTransitionFunction},
+    types::IndexFunction,
+    Sequence,
+};
+
+/// Ranges shorter than this are generated sequentially.
+const MIN_PARALLEL_LEN: usize = 4096;
+
+impl<T> Sequence<T, WithoutInitialElements, WithoutTransitionFunction> {
+    /// Adds a function of the index to the sequence,
+    /// the elements of the sequence can be generated in parallel.
+    /// The function reads no preceding elements, so the look-back is 0.
+    pub fn index_function(
+        self,
+        index_func: IndexFunction<T>,
+    ) -> Sequence<T, WithoutInitialElements, WithTransitionFunction<T, WithoutInitialElements>>
+    {
+        let mut sequence = self.map_states(|initial_elements, _| {
+            (
+                initial_elements,
+                WithTransitionFunction::new_index(index_func),
+            )
+        });
+        sequence.look_back = Some(0);
+
+        sequence
+    }
+}
+
+impl<T: Send, I> Sequence<T, I, WithTransitionFunction<T, I>> {
+    /// Generates the specified number of elements in parallel if they are generated
+    /// by a function of the index (see `index_function`), or sequentially otherwise.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the generation fails.
+    pub fn par_generate(&mut self, number_of_elements: usize) {
+        if number_of_elements > 0 {
+            self.par_generate_nth_element(self.next_index() + number_of_elements - 1);
+        }
+    }
+
+    /// Generates the nth element and all the preceding elements in parallel
+    /// if they are generated by a function of the index, or sequentially otherwise.
+    fn par_generate_nth_element(&mut self, nth_element: usize) {
+        let indices = self.next_index()..nth_element + 1;
+
+        let index_func = match self.trans_func.index_function(&indices) {
+            Some(index_func) if indices.len() >= MIN_PARALLEL_LEN => index_func,
+            _ => return self.generate_nth_element(nth_element),
+        };
+
+        #[cfg(feature = "tracing")]
+        let _span = tracing::debug_span!("par_generate", start = indices.start, end = indices.end)
+            .entered();
+
+        for observer in &mut self.observers {
+            observer.on_batch_start(indices.clone());
+        }
+
+        let mut elements = map_in_parallel(indices.clone(), index_func);
+
+        for (index, (element, clone)) in self.overrides.range(indices.clone()) {
+            elements[index - indices.start] = clone(element);
+        }
+
+        let generated = elements.len();
+
+        for (current_element_index, new_element) in indices.zip(elements) {
+            self.push_element(current_element_index, new_element);
+        }
+
+        for observer in &mut self.observers {
+            observer.on_batch_end(generated);
+        }
+    }
+}
+
+impl<T: Send>
+    Sequence<T, WithoutInitialElements, WithTransitionFunction<T, WithoutInitialElements>>
+{
+    /// Pre generate the specified number elements on the sequence in parallel,
+    /// see `par_generate`.
+    pub fn par_pre_generate(mut self, number_of_elements: usize) -> Self {
+        self.par_generate(number_of_elements);
+        self
+    }
+}
+
+/// Maps the indices in parallel with rayon, the results are in the order of the indices.
+#[cfg(feature = "rayon")]
+pub(super) fn map_in_parallel<U: Send>(
+    indices: Range<usize>,
+    f: impl Fn(usize) -> U + Send + Sync,
+) -> Vec<U> {
+    use rayon::prelude::*;
+
+    indices.into_par_iter().map(f).collect()
+}
+
+/// Maps the indices in parallel, in one chunk per available thread,
+/// the results are in the order of the indices.
+#[cfg(not(feature = "rayon"))]
+pub(super) fn map_in_parallel<U: Send>(
+    indices: Range<usize>,
+    f: impl Fn(usize) -> U + Send + Sync,
+) -> Vec<U> {
+    use std::{panic, thread};
+
+    let threads = thread::available_parallelism().map_or(1, |threads| threads.get());
+    let chunk_len = indices.len().div_ceil(threads).max(1);
+    let f = &f;
+
+    thread::scope(|scope| {
+        let chunks: Vec<_> = indices
+            .clone()
+            .step_by(chunk_len)
+            .map(|chunk_start| {
+                let chunk = chunk_start..(chunk_start + chunk_len).min(indices.end);
+                scope.spawn(move || chunk.map(f).collect::<Vec<_>>())
+            })
+            .collect();
+
+        let mut elements = Vec::with_capacity(indices.len());
+
+        for chunk in chunks {
+            elements.extend(
+                chunk
+                    .join()
+                    .unwrap_or_else(|payload| panic::resume_unwind(payload)),
+            );
+        }
+
+        elements
+    })
+}
@@ -1,6 +1,14 @@
 //! This module defines the states of the Sequence type
 
-use super::types::TransitionFunction;
+use std::{any::Any, fmt, ops::Range};
+
+use super::{
+    error::GenerationError,
+    types::{
+        BatchedTransitionFunction, BoxedTransitionFunction, FallibleTransitionFunction,
+        GenerationResult, IndexFunction, ParameterizedTransitionFunction, TransitionFunction,
+    },
+};
 
 use crate::sequence_part::types::AliveElementsPart;
 
@@ -31,6 +39,24 @@ impl WithInitialElements {
     }
 }
 
+/// Initial elements states of the Sequence type.
+pub trait InitialElementsState {
+    /// Returns the length of the initial elements.
+    fn initial_elements_len(&self) -> usize;
+}
+
+impl InitialElementsState for WithoutInitialElements {
+    fn initial_elements_len(&self) -> usize {
+        0
+    }
+}
+
+impl InitialElementsState for WithInitialElements {
+    fn initial_elements_len(&self) -> usize {
+        self.len()
+    }
+}
+
 /// A type that represents when the sequence
 /// has no transition function.
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
@@ -38,26 +64,327 @@ pub struct WithoutTransitionFunction;
 
 /// A type that represents when the sequence
 /// has transition function.
+///
+/// The transition can be a boxed closure (e.g. a derived sequence) or own a parameter,
+/// so this type is only `Debug`, it is not `Clone`, `Copy`, `PartialEq` or `Eq`.
+pub struct WithTransitionFunction<T, I> {
+    transition: Transition<T, I>,
+    segments: Vec<(usize, Transition<T, I>)>,
+}
+
+/// How the alive elements are handled when the transition
+/// of a sequence is replaced from an index.
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
-pub struct WithTransitionFunction<T, I>(TransitionFunction<T, I>);
+pub enum ReplacementMode {
+    /// The alive elements are kept, the new transition generates
+    /// the elements from the index that are not alive yet.
+    KeepAlive,
+    /// The alive elements from the index onward are removed,
+    /// so they are generated again with the new transition.
+    Invalidate,
+}
+
+impl<T, I> fmt::Debug for WithTransitionFunction<T, I> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("WithTransitionFunction")
+            .field("transition", &self.transition.kind())
+            .field("segments", &self.segment_starts().collect::<Vec<_>>())
+            .finish()
+    }
+}
+
+/// The rule that generates the elements of a sequence.
+enum Transition<T, I> {
+    /// A plain transition function.
+    Function(TransitionFunction<T, I>),
+    /// A transition function that can fail.
+    Fallible(FallibleTransitionFunction<T, I>),
+    /// A transition built on top of another kind of function (e.g. a recurrence).
+    Boxed(BoxedTransitionFunction<T, I>),
+    /// A transition function that generates blocks of elements of the given length.
+    Batched(usize, BatchedTransitionFunction<T, I>),
+    /// A function of the index only.
+    Index(IndexFunction<T>),
+    /// A transition function with the parameter it receives.
+    Parameterized(Box<dyn ParameterizedTransition<T, I>>),
+}
+
+impl<T, I> Transition<T, I> {
+    /// Returns the name of the kind of transition.
+    fn kind(&self) -> &'static str {
+        match self {
+            Self::Function(_) => "Function",
+            Self::Fallible(_) => "Fallible",
+            Self::Boxed(_) => "Boxed",
+            Self::Batched(..) => "Batched",
+            Self::Index(_) => "Index",
+            Self::Parameterized(_) => "Parameterized",
+        }
+    }
+}
+
+/// A transition function that owns the parameter it receives,
+/// the parameter keeps its type so it is not downcast for each element.
+trait ParameterizedTransition<T, I>: Send {
+    /// Runs the transition function with the parameter.
+    fn run(
+        &self,
+        alive_elements_part: AliveElementsPart<'_, T, I>,
+        current_element_index: usize,
+    ) -> GenerationResult<T>;
+
+    /// Returns the parameter.
+    fn parameter(&self) -> &dyn Any;
+
+    /// Returns the parameter mutably.
+    fn parameter_mut(&mut self) -> &mut dyn Any;
+}
+
+/// A parameter with the transition function that receives it.
+struct WithParameter<T, I, P> {
+    parameter: P,
+    trans_func: ParameterizedTransitionFunction<T, I, P>,
+}
+
+impl<T, I, P: Send + 'static> ParameterizedTransition<T, I> for WithParameter<T, I, P> {
+    fn run(
+        &self,
+        alive_elements_part: AliveElementsPart<'_, T, I>,
+        current_element_index: usize,
+    ) -> GenerationResult<T> {
+        (self.trans_func)(alive_elements_part, current_element_index, &self.parameter)
+    }
+
+    fn parameter(&self) -> &dyn Any {
+        &self.parameter
+    }
+
+    fn parameter_mut(&mut self) -> &mut dyn Any {
+        &mut self.parameter
+    }
+}
 
 impl<T, I> WithTransitionFunction<T, I> {
     /// Create new instance.
     pub(super) fn new(trans_func: TransitionFunction<T, I>) -> Self {
-        Self(trans_func)
+        Self::from_transition(Transition::Function(trans_func))
     }
 
-    /// Returns the transition function.
-    pub(super) fn transition_function(&self) -> TransitionFunction<T, I> {
-        self.0
+    /// Create new instance from a fallible transition function.
+    pub(super) fn new_fallible(trans_func: FallibleTransitionFunction<T, I>) -> Self {
+        Self::from_transition(Transition::Fallible(trans_func))
+    }
+
+    /// Create new instance from a boxed transition.
+    pub(super) fn new_boxed(trans_func: BoxedTransitionFunction<T, I>) -> Self {
+        Self::from_transition(Transition::Boxed(trans_func))
+    }
+
+    /// Create new instance from a batched transition function.
+    pub(super) fn new_batched(
+        block_len: usize,
+        trans_func: BatchedTransitionFunction<T, I>,
+    ) -> Self {
+        Self::from_transition(Transition::Batched(block_len.max(1), trans_func))
+    }
+
+    /// Create new instance from a function of the index.
+    pub(super) fn new_index(index_func: IndexFunction<T>) -> Self {
+        Self::from_transition(Transition::Index(index_func))
+    }
+
+    /// Create new instance from a transition function and the parameter it receives.
+    pub(super) fn new_parameterized<P: Send + 'static>(
+        parameter: P,
+        trans_func: ParameterizedTransitionFunction<T, I, P>,
+    ) -> Self
+    where
+        T: 'static,
+        I: 'static,
+    {
+        Self::from_transition(Transition::Parameterized(Box::new(WithParameter {
+            parameter,
+            trans_func,
+        })))
+    }
+
+    /// Create new instance from a transition without segments.
+    fn from_transition(transition: Transition<T, I>) -> Self {
+        Self {
+            transition,
+            segments: Vec::new(),
+        }
+    }
+
+    /// Makes the transition function generate the elements from the index onward,
+    /// the segments that start after the index are removed.
+    pub(super) fn replace_from(&mut self, index: usize, trans_func: Self) {
+        self.segments.retain(|(start, _)| *start < index);
+        self.segments.push((index, trans_func.transition));
+    }
+
+    /// Returns the indices where the transition function changes (in order).
+    pub(super) fn segment_starts(&self) -> impl Iterator<Item = usize> + '_ {
+        self.segments.iter().map(|(start, _)| *start)
+    }
+
+    /// Returns the function of the index that generates all the elements of the indices,
+    /// or None if they are not generated by a single function of the index.
+    pub(super) fn index_function(&self, indices: &Range<usize>) -> Option<IndexFunction<T>> {
+        let segment = self
+            .segments
+            .iter()
+            .rposition(|(start, _)| *start <= indices.start);
+        let transition = segment.map_or(&self.transition, |segment| &self.segments[segment].1);
+        let crosses_segment = self
+            .segments
+            .get(segment.map_or(0, |segment| segment + 1))
+            .is_some_and(|(start, _)| *start < indices.end);
+
+        match transition {
+            Transition::Index(index_func) if !crosses_segment => Some(*index_func),
+            _ => None,
+        }
+    }
+
+    /// Returns the parameter of the transition function (the one that is not
+    /// a segment) if it receives a parameter of this type.
+    pub(super) fn parameter<P: 'static>(&self) -> Option<&P> {
+        match &self.transition {
+            Transition::Parameterized(trans_func) => trans_func.parameter().downcast_ref(),
+            _ => None,
+        }
+    }
+
+    /// Returns the parameter of the transition function mutably, see `parameter`.
+    pub(super) fn parameter_mut<P: 'static>(&mut self) -> Option<&mut P> {
+        match &mut self.transition {
+            Transition::Parameterized(trans_func) => trans_func.parameter_mut().downcast_mut(),
+            _ => None,
+        }
+    }
+
+    /// Checks if the transition function (or a segment) generates blocks of elements.
+    pub(super) fn generates_blocks(&self) -> bool {
+        std::iter::once(&self.transition)
+            .chain(self.segments.iter().map(|(_, transition)| transition))
+            .any(|transition| matches!(transition, Transition::Batched(..)))
+    }
+
+    /// Runs the transition function of the index, that does not generate blocks.
+    pub(super) fn element(
+        &self,
+        alive_elements_part: AliveElementsPart<'_, T, I>,
+        current_element_index: usize,
+    ) -> GenerationResult<T> {
+        if self.segments.is_empty() {
+            return self
+                .transition
+                .element(alive_elements_part, current_element_index);
+        }
+
+        let transition = self
+            .segments
+            .iter()
+            .rev()
+            .find(|(start, _)| *start <= current_element_index)
+            .map_or(&self.transition, |(_, transition)| transition);
+
+        transition.element(alive_elements_part, current_element_index)
     }
 
-    /// Runs the transition function.
+    /// Runs the transition function from the index and returns the generated element,
+    /// or pushes a block of elements that ends before `end` into the buffer and returns None.
     pub(super) fn run(
         &self,
         alive_elements_part: AliveElementsPart<'_, T, I>,
         current_element_index: usize,
-    ) -> T {
-        (self.transition_function())(alive_elements_part, current_element_index)
+        end: usize,
+        buffer: &mut Vec<T>,
+    ) -> GenerationResult<Option<T>> {
+        if self.segments.is_empty() {
+            return self
+                .transition
+                .run(alive_elements_part, current_element_index, end, buffer);
+        }
+
+        // The segments are ordered by their start, a block does not cross the next segment.
+        let segment = self
+            .segments
+            .iter()
+            .rposition(|(start, _)| *start <= current_element_index);
+        let transition = segment.map_or(&self.transition, |segment| &self.segments[segment].1);
+        let end = self
+            .segments
+            .get(segment.map_or(0, |segment| segment + 1))
+            .map_or(end, |(start, _)| end.min(*start));
+
+        transition.run(alive_elements_part, current_element_index, end, buffer)
+    }
+}
+
+impl<T, I> Transition<T, I> {
+    /// Runs the transition, see `WithTransitionFunction::run`.
+    fn run(
+        &self,
+        alive_elements_part: AliveElementsPart<'_, T, I>,
+        current_element_index: usize,
+        end: usize,
+        buffer: &mut Vec<T>,
+    ) -> GenerationResult<Option<T>> {
+        match self {
+            Transition::Batched(block_len, trans_func) => {
+                // The blocks are aligned to multiples of the block length.
+                let block_end = (current_element_index / block_len + 1) * block_len;
+                let indices = current_element_index..block_end.min(end);
+                let buffer_len = buffer.len();
+
+                trans_func(alive_elements_part, indices.clone(), buffer)?;
+
+                let generated = buffer.len() - buffer_len;
+                if generated != indices.len() {
+                    buffer.truncate(buffer_len);
+
+                    return Err(GenerationError::Failed {
+                        index: current_element_index,
+                        message: format!(
+                            "The batched transition generated {generated} elements for {} indices.",
+                            indices.len()
+                        ),
+                    });
+                }
+
+                Ok(None)
+            }
+            _ => self
+                .element(alive_elements_part, current_element_index)
+                .map(Some),
+        }
+    }
+
+    /// Runs the transition that does not generate blocks, see `WithTransitionFunction::element`.
+    #[inline]
+    fn element(
+        &self,
+        alive_elements_part: AliveElementsPart<'_, T, I>,
+        current_element_index: usize,
+    ) -> GenerationResult<T> {
+        match self {
+            Transition::Function(trans_func) => {
+                Ok(trans_func(alive_elements_part, current_element_index))
+            }
+            Transition::Fallible(trans_func) => {
+                trans_func(alive_elements_part, current_element_index)
+            }
+            Transition::Boxed(trans_func) => trans_func(alive_elements_part, current_element_index),
+            Transition::Index(index_func) => Ok(index_func(current_element_index)),
+            Transition::Parameterized(trans_func) => {
+                trans_func.run(alive_elements_part, current_element_index)
+            }
+            Transition::Batched(..) => Err(GenerationError::Failed {
+                index: current_element_index,
+                message: String::from("The batched transition generates blocks of elements."),
+            }),
+        }
     }
 }
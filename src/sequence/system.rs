@@ -0,0 +1,204 @@
+//! This module defines systems of mutually recursive sequences,
+//! where the transition function of each member reads the elements of all members.
+//!
+//! The members are generated index by index, and for the same index in the order
+//! they were added, so the transition of a member can read the elements of all members
+//! before the current index and the current element of the members added before it.
+//! The members are sequences, their elements are read through the same parts
+//! (alive elements and ranges) as any other sequence.
+
+use super::{
+    error::GenerationError,
+    states::{WithInitialElements, WithTransitionFunction},
+    types::{GenerationResult, SystemTransitionFunction},
+    Sequence, SharedSequenceBehavior,
+};
+
+use crate::sequence_part::types::{AliveElementsPart, RangePartImmutResult};
+
+/// A sequence that is a member of a system, its elements are generated by the system.
+type MemberSequence<T> =
+    Sequence<T, WithInitialElements, WithTransitionFunction<T, WithInitialElements>>;
+
+/// A system of mutually recursive sequences,
+/// the members are identified by the order they were added in (starting from 0).
+pub struct SequenceSystem<T> {
+    members: Vec<Member<T>>,
+}
+
+/// A member of a system of sequences.
+struct Member<T> {
+    sequence: MemberSequence<T>,
+    trans_func: SystemTransitionFunction<T>,
+}
+
+/// Read access to the alive elements of all members of a system of sequences.
+pub struct SystemView<'a, T> {
+    members: &'a [Member<T>],
+    generating: usize,
+}
+
+impl<T> SequenceSystem<T> {
+    /// Creates a system without members.
+    pub fn new() -> Self {
+        Self {
+            members: Vec::new(),
+        }
+    }
+
+    /// Adds a member with its initial elements and transition function.
+    pub fn member(
+        mut self,
+        initial_elements: Vec<T>,
+        trans_func: SystemTransitionFunction<T>,
+    ) -> Self {
+        // The elements are pushed by the system, the transition function of the sequence
+        // is only reached if an element is requested from the sequence itself.
+        let sequence = Sequence::new()
+            .initial_elements(initial_elements)
+            .fallible_transition_function(|_, current_element_index| {
+                Err(GenerationError::Failed {
+                    index: current_element_index,
+                    message: String::from("The members of a system are generated by the system."),
+                })
+            });
+
+        self.members.push(Member {
+            sequence,
+            trans_func,
+        });
+
+        self
+    }
+
+    /// Returns the number of members.
+    pub fn members_len(&self) -> usize {
+        self.members.len()
+    }
+
+    /// Returns a sequence part that represents the alive elements of the member.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the member does not exist.
+    pub fn alive_elements(&self, member: usize) -> AliveElementsPart<'_, T, WithInitialElements> {
+        self.members[member].sequence.alive_elements()
+    }
+
+    /// Returns a sequence part that represents the range of the member,
+    /// the elements of the range are not generated.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the member does not exist.
+    pub fn range(
+        &self,
+        member: usize,
+        start: usize,
+        end: usize,
+    ) -> RangePartImmutResult<'_, T, WithInitialElements> {
+        self.members[member].sequence.range(start, end)
+    }
+
+    /// Generates the nth element of all the members and all the preceding elements,
+    /// the elements generated before a failure stay alive.
+    pub fn try_generate_nth_element(&mut self, nth_element: usize) -> GenerationResult<()> {
+        let Some(start) = self
+            .members
+            .iter()
+            .map(|member| member.sequence.next_index())
+            .min()
+        else {
+            return Ok(());
+        };
+
+        for current_element_index in start..nth_element + 1 {
+            for member in 0..self.members.len() {
+                if self.members[member].sequence.next_index() != current_element_index {
+                    continue;
+                }
+
+                let view = SystemView {
+                    members: &self.members,
+                    generating: current_element_index,
+                };
+                let new_element = (self.members[member].trans_func)(view, current_element_index)?;
+
+                self.members[member]
+                    .sequence
+                    .push_element(current_element_index, new_element);
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Returns a reference to the nth element of the member,
+    /// or the error that prevented it from being generated.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the member does not exist.
+    pub fn try_nth_element(&mut self, member: usize, index: usize) -> GenerationResult<&T> {
+        assert!(member < self.members.len(), "The member does not exist.");
+
+        self.try_generate_nth_element(index)?;
+
+        Ok(self.members[member]
+            .sequence
+            .nth_element_without_generation(index)
+            .expect("The generated elements are alive."))
+    }
+
+    /// Returns a reference to the nth element of the member.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the member does not exist or if the generation fails.
+    pub fn nth_element(&mut self, member: usize, index: usize) -> &T {
+        self.try_nth_element(member, index)
+            .unwrap_or_else(|error| panic!("{error}"))
+    }
+
+    /// Removes all the generated elements, the initial elements are kept.
+    pub fn clear(&mut self) {
+        for member in &mut self.members {
+            member.sequence.clear();
+        }
+    }
+}
+
+impl<T> Default for SequenceSystem<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T> SystemView<'_, T> {
+    /// Returns the number of members.
+    pub fn members_len(&self) -> usize {
+        self.members.len()
+    }
+
+    /// Returns a reference to the nth element of the member if it is alive.
+    pub fn nth_element(&self, member: usize, index: usize) -> Option<&T> {
+        self.members
+            .get(member)?
+            .sequence
+            .nth_element_without_generation(index)
+    }
+
+    /// Returns a reference to the nth element of the member,
+    /// or an error if the member does not exist or the element is not alive.
+    pub fn try_nth_element(&self, member: usize, index: usize) -> GenerationResult<&T> {
+        self.nth_element(member, index)
+            .ok_or(GenerationError::UnavailableElement {
+                generating: self.generating,
+                requested: index,
+                alive_len: self
+                    .members
+                    .get(member)
+                    .map_or(0, |member| member.sequence.alive_elements_len()),
+            })
+    }
+}
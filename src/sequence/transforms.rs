@@ -0,0 +1,120 @@
+//! This module defines lazy transforms of sequences (differences, partial sums, etc...),
+//! the transformed sequence owns its source and pulls the source elements on demand.
+
+use std::cell::RefCell;
+
+use super::{
+    error::GenerationError,
+    states::{WithTransitionFunction, WithoutInitialElements},
+    types::{DerivedSequence, GenerationResult},
+    Sequence,
+};
+
+use crate::{number::Number, sequence_part::types::AliveElementsPart};
+
+impl<T: Send + 'static, I: Send + 'static> Sequence<T, I, WithTransitionFunction<T, I>> {
+    /// Creates a sequence that owns this sequence, the element function receives
+    /// this sequence, the alive elements of the derived sequence and the index
+    /// of the current element in generation.
+    pub(crate) fn derive<U>(
+        self,
+        element: impl Fn(
+                &mut Self,
+                AliveElementsPart<'_, U, WithoutInitialElements>,
+                usize,
+            ) -> GenerationResult<U>
+            + Send
+            + 'static,
+    ) -> DerivedSequence<U> {
+        let source = RefCell::new(self);
+
+        Sequence::new().boxed_transition_function(
+            Box::new(move |alive_elements, current_element_index| {
+                element(
+                    &mut source.borrow_mut(),
+                    alive_elements,
+                    current_element_index,
+                )
+            }),
+            None,
+        )
+    }
+}
+
+impl<T: Number + Send + 'static, I: Send + 'static> Sequence<T, I, WithTransitionFunction<T, I>> {
+    /// Returns the sequence of the first differences, `d(n) = a(n + 1) - a(n)`.
+    pub fn differences(self) -> DerivedSequence<T> {
+        self.derive(|source, _, current_element_index| {
+            let current = source.try_nth_element(current_element_index)?.clone();
+            let next = source.try_nth_element(current_element_index + 1)?;
+
+            next.checked_sub(&current)
+                .ok_or_else(|| not_representable(current_element_index, "difference"))
+        })
+    }
+
+    /// Returns the sequence of the k-th differences, the 0-th differences are the sequence itself.
+    pub fn kth_differences(self, k: usize) -> DerivedSequence<T> {
+        let mut differences = self.derive(|source, _, current_element_index| {
+            source.try_nth_element(current_element_index).cloned()
+        });
+
+        for _ in 0..k {
+            differences = differences.differences();
+        }
+
+        differences
+    }
+
+    /// Returns the sequence of the partial sums, `s(n) = a(0) + ... + a(n)`.
+    pub fn partial_sums(self) -> DerivedSequence<T> {
+        self.derive(|source, alive_elements, current_element_index| {
+            let element = source.try_nth_element(current_element_index)?;
+
+            if current_element_index == 0 {
+                return Ok(element.clone());
+            }
+
+            alive_elements
+                .try_nth_element(current_element_index - 1)?
+                .checked_add(element)
+                .ok_or_else(|| not_representable(current_element_index, "partial sum"))
+        })
+    }
+
+    /// Returns the sequence of the running products, `p(n) = a(0) * ... * a(n)`.
+    pub fn running_products(self) -> DerivedSequence<T> {
+        self.derive(|source, alive_elements, current_element_index| {
+            let element = source.try_nth_element(current_element_index)?;
+
+            if current_element_index == 0 {
+                return Ok(element.clone());
+            }
+
+            alive_elements
+                .try_nth_element(current_element_index - 1)?
+                .checked_mul(element)
+                .ok_or_else(|| not_representable(current_element_index, "running product"))
+        })
+    }
+
+    /// Returns the sequence of the successive ratios, `r(n) = a(n + 1) / a(n)`.
+    /// The ratios of integer sequences are truncated.
+    pub fn ratios(self) -> DerivedSequence<T> {
+        self.derive(|source, _, current_element_index| {
+            let current = source.try_nth_element(current_element_index)?.clone();
+            let next = source.try_nth_element(current_element_index + 1)?;
+
+            next.checked_div(&current)
+                .ok_or_else(|| not_representable(current_element_index, "ratio"))
+        })
+    }
+}
+
+/// Returns the error of a transformed element that is not representable.
+fn not_representable(index: usize, transform: &str) -> GenerationError {
+    GenerationError::Failed {
+        index,
+        message: format!("The {transform} is not representable."),
+    }
+}
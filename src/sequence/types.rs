@@ -1,5 +1,14 @@
 //! This module defines type aliases for the sequence module
 
+use std::{cell::RefCell, ops::Range, rc::Rc};
+
+use super::{
+    error::GenerationError,
+    states::{WithTransitionFunction, WithoutInitialElements},
+    system::SystemView,
+    Sequence,
+};
+
 use crate::sequence_part::types::AliveElementsPart;
 
 /// A type that represents the transition function.
@@ -7,3 +16,53 @@ use crate::sequence_part::types::AliveElementsPart;
 /// the sequence, and the second is the index of the
 /// current element in generation.
 pub type TransitionFunction<T, I> = fn(AliveElementsPart<'_, T, I>, usize) -> T;
+
+/// A type that represents a function of the index only,
+/// the elements it generates can be generated in parallel.
+pub type IndexFunction<T> = fn(usize) -> T;
+
+/// A type that represents a transition function that can fail.
+/// The parameters are the same as the transition function ones,
+/// errors are propagated out of the generation.
+pub type FallibleTransitionFunction<T, I> =
+    fn(AliveElementsPart<'_, T, I>, usize) -> Result<T, GenerationError>;
+
+/// Generation result that is returned by the fallible generation methods.
+pub type GenerationResult<T> = Result<T, GenerationError>;
+
+/// A type that represents a transition function that receives the parameter of the sequence.
+/// The first two parameters are the same as the transition function ones,
+/// and the third is the parameter. Errors are propagated out of the generation.
+pub type ParameterizedTransitionFunction<T, I, P> =
+    fn(AliveElementsPart<'_, T, I>, usize, &P) -> Result<T, GenerationError>;
+
+/// A type that represents a transition function that generates a block of elements.
+/// The first parameter is the alive elements part of the sequence, the second is
+/// the indices of the block, and the third is the buffer the elements are pushed into
+/// (one element per index, in order). Errors are propagated out of the generation.
+pub type BatchedTransitionFunction<T, I> =
+    fn(AliveElementsPart<'_, T, I>, Range<usize>, &mut Vec<T>) -> Result<(), GenerationError>;
+
+/// A type that represents a transition that is stored on the heap,
+/// it is used to build transitions on top of other kinds of functions.
+pub(crate) type BoxedTransitionFunction<T, I> =
+    Box<dyn Fn(AliveElementsPart<'_, T, I>, usize) -> Result<T, GenerationError> + Send>;
+
+/// A type that represents a sequence shared between several owners (e.g. cursors).
+pub type SharedSequence<T, I> = Rc<RefCell<Sequence<T, I, WithTransitionFunction<T, I>>>>;
+
+/// A type that represents the transition function of a member of a sequence system.
+/// The first parameter gives read access to the alive elements of all members,
+/// and the second is the index of the current element in generation.
+pub type SystemTransitionFunction<T> = fn(SystemView<'_, T>, usize) -> Result<T, GenerationError>;
+
+/// A type that represents a recurrence of order K.
+/// The first parameter is the window of the last K elements
+/// (oldest first), and the second is the index of the
+/// current element in generation.
+pub type RecurrenceFunction<T, const K: usize> = fn([&T; K], usize) -> T;
+
+/// A type that represents a sequence derived from other sequences (e.g. by a transform),
+/// its elements are generated by pulling the elements of the other sequences on demand.
+pub type DerivedSequence<T> =
+    Sequence<T, WithoutInitialElements, WithTransitionFunction<T, WithoutInitialElements>>;
@@ -10,6 +10,7 @@
 
 pub mod error;
 pub mod states;
+pub mod statistics;
 pub mod types;
 
 use self::{
@@ -20,6 +21,8 @@ use self::{
     },
 };
 
+use crate::sequence::{error::GenerationError, types::GenerationResult};
+
 /// This type represents a part of a sequence.
 /// it could be the alive elements or a custom
 /// range of the sequence.
@@ -45,7 +48,7 @@ pub trait SharedSequencePartBehavior<'a, T, I> {
 }
 
 impl<'a, T, I> AliveElementsPart<'a, T, I> {
-    /// Create a new instance that represents 
+    /// Create a new instance that represents
     /// the alive elements part of a sequence.
     pub(super) fn new(parent_sequence: ParentSequenceRef<'a, T, I>) -> Self {
         Self {
@@ -65,14 +68,28 @@ impl<'a, T, I> AliveElementsPart<'a, T, I> {
         self.parent_sequence.nth_element_without_generation(index)
     }
 
+    /// Returns the nth element of the alive elements part, or an error that
+    /// describes the element being generated when the nth element is not alive.
+    /// This is meant to be used inside fallible transition functions.
+    pub fn try_nth_element(&self, index: usize) -> GenerationResult<&T> {
+        self.nth_element(index)
+            .ok_or(GenerationError::UnavailableElement {
+                generating: self.parent_sequence.next_index(),
+                requested: index,
+                alive_len: self.len(),
+            })
+    }
+
     /// Returns the first element of the alive elements part.
     pub fn first_element(&self) -> Option<&T> {
-        self.nth_element(0)
+        self.parent_sequence.alive_element_at(0)
     }
 
     /// Returns the last element of the alive elements part.
     pub fn last_element(&self) -> Option<&T> {
-        self.nth_element(self.len() - 1)
+        self.len()
+            .checked_sub(1)
+            .and_then(|position| self.parent_sequence.alive_element_at(position))
     }
 }
 
@@ -197,7 +214,7 @@ impl<'a, T: Clone, I> Iterator for AliveElementsPart<'a, T, I> {
         }
 
         self.iter_index += 1;
-        self.nth_element(iter_index).cloned()
+        self.parent_sequence.alive_element_at(iter_index).cloned()
     }
 }
 
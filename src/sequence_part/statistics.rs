@@ -0,0 +1,489 @@
+//! This module defines streaming statistics over sequence parts,
+//! the statistics are computed in one pass over the elements without collecting them,
+//! and the statistics of several parts (e.g. chunks generated in parallel) can be merged.
+
+use std::collections::BTreeMap;
+
+use super::types::{AliveElementsPart, RangePartImmut, RangePartMut};
+
+use crate::number::Number;
+
+/// A statistic computed in one pass over a stream of values.
+pub trait StreamingStatistic {
+    /// Adds a value to the statistic.
+    fn push(&mut self, value: f64);
+
+    /// Merges the statistic of another part of the stream into this one.
+    fn merge(&mut self, other: &Self);
+}
+
+/// Count, sum, mean, variance, min and max of a stream of values.
+/// The sum is compensated and the variance is computed with Welford's algorithm,
+/// so both stay accurate over long streams.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Summary {
+    count: u64,
+    sum: f64,
+    compensation: f64,
+    mean: f64,
+    m2: f64,
+    min: f64,
+    max: f64,
+}
+
+/// Quantile sketch (DDSketch) of a stream of values, the quantiles are
+/// within the relative accuracy of the exact quantiles. NaN values are ignored.
+#[derive(Debug, Clone, PartialEq)]
+pub struct QuantileSketch {
+    relative_accuracy: f64,
+    gamma_ln: f64,
+    positive: BTreeMap<i32, u64>,
+    negative: BTreeMap<i32, u64>,
+    zero_count: u64,
+    count: u64,
+}
+
+/// Histogram of a stream of values with bins of equal width,
+/// the values outside of the bins are counted apart. NaN values are ignored.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Histogram {
+    start: f64,
+    end: f64,
+    counts: Vec<u64>,
+    underflow: u64,
+    overflow: u64,
+}
+
+impl Summary {
+    /// Creates an empty summary.
+    pub fn new() -> Self {
+        Self {
+            count: 0,
+            sum: 0.0,
+            compensation: 0.0,
+            mean: 0.0,
+            m2: 0.0,
+            min: f64::INFINITY,
+            max: f64::NEG_INFINITY,
+        }
+    }
+
+    /// Returns the number of values.
+    pub fn count(&self) -> u64 {
+        self.count
+    }
+
+    /// Returns the sum of the values, the values are f64 so the sum of integers
+    /// is only exact below 2^53 (see `exact_sum` on the parts for an exact sum).
+    pub fn sum(&self) -> f64 {
+        self.sum + self.compensation
+    }
+
+    /// Returns the mean of the values, or None if there are none.
+    pub fn mean(&self) -> Option<f64> {
+        (self.count > 0).then_some(self.mean)
+    }
+
+    /// Returns the population variance of the values, or None if there are none.
+    pub fn variance(&self) -> Option<f64> {
+        (self.count > 0).then(|| self.m2 / self.count as f64)
+    }
+
+    /// Returns the sample variance of the values, or None if there are less than two.
+    pub fn sample_variance(&self) -> Option<f64> {
+        (self.count > 1).then(|| self.m2 / (self.count - 1) as f64)
+    }
+
+    /// Returns the population standard deviation of the values, or None if there are none.
+    pub fn std_dev(&self) -> Option<f64> {
+        self.variance().map(f64::sqrt)
+    }
+
+    /// Returns the smallest value, or None if there are none.
+    pub fn min(&self) -> Option<f64> {
+        (self.count > 0).then_some(self.min)
+    }
+
+    /// Returns the largest value, or None if there are none.
+    pub fn max(&self) -> Option<f64> {
+        (self.count > 0).then_some(self.max)
+    }
+
+    /// Adds the value to the compensated sum (Neumaier's algorithm).
+    fn add_to_sum(&mut self, value: f64) {
+        let sum = self.sum + value;
+
+        if self.sum.abs() >= value.abs() {
+            self.compensation += (self.sum - sum) + value;
+        } else {
+            self.compensation += (value - sum) + self.sum;
+        }
+
+        self.sum = sum;
+    }
+}
+
+impl Default for Summary {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl StreamingStatistic for Summary {
+    fn push(&mut self, value: f64) {
+        self.count += 1;
+        self.add_to_sum(value);
+
+        let delta = value - self.mean;
+        self.mean += delta / self.count as f64;
+        self.m2 += delta * (value - self.mean);
+
+        self.min = self.min.min(value);
+        self.max = self.max.max(value);
+    }
+
+    fn merge(&mut self, other: &Self) {
+        if other.count == 0 {
+            return;
+        }
+
+        if self.count == 0 {
+            *self = *other;
+            return;
+        }
+
+        let count = self.count + other.count;
+        let delta = other.mean - self.mean;
+
+        self.mean += delta * other.count as f64 / count as f64;
+        self.m2 += other.m2 + delta * delta * self.count as f64 * other.count as f64 / count as f64;
+        self.count = count;
+
+        self.add_to_sum(other.sum);
+        self.add_to_sum(other.compensation);
+
+        self.min = self.min.min(other.min);
+        self.max = self.max.max(other.max);
+    }
+}
+
+impl QuantileSketch {
+    /// Creates an empty sketch whose quantiles are within the relative accuracy
+    /// (e.g. 0.01 for 1%) of the exact quantiles.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the relative accuracy is not between 0 and 1 (exclusive).
+    pub fn new(relative_accuracy: f64) -> Self {
+        assert!(
+            relative_accuracy > 0.0 && relative_accuracy < 1.0,
+            "The relative accuracy must be between 0 and 1."
+        );
+
+        let gamma = (1.0 + relative_accuracy) / (1.0 - relative_accuracy);
+
+        Self {
+            relative_accuracy,
+            gamma_ln: gamma.ln(),
+            positive: BTreeMap::new(),
+            negative: BTreeMap::new(),
+            zero_count: 0,
+            count: 0,
+        }
+    }
+
+    /// Returns the relative accuracy of the sketch.
+    pub fn relative_accuracy(&self) -> f64 {
+        self.relative_accuracy
+    }
+
+    /// Returns the number of values.
+    pub fn count(&self) -> u64 {
+        self.count
+    }
+
+    /// Returns the approximate quantile `q` (between 0 and 1) of the values,
+    /// or None if there are none.
+    pub fn quantile(&self, q: f64) -> Option<f64> {
+        if self.count == 0 {
+            return None;
+        }
+
+        let rank = (q.clamp(0.0, 1.0) * (self.count - 1) as f64).floor() as u64;
+        let mut seen = 0;
+
+        // The values are visited in increasing order.
+        for (key, count) in self.negative.iter().rev() {
+            seen += count;
+            if seen > rank {
+                return Some(-self.bucket_value(*key));
+            }
+        }
+
+        seen += self.zero_count;
+        if seen > rank {
+            return Some(0.0);
+        }
+
+        for (key, count) in &self.positive {
+            seen += count;
+            if seen > rank {
+                return Some(self.bucket_value(*key));
+            }
+        }
+
+        None
+    }
+
+    /// Returns the approximate median of the values, or None if there are none.
+    pub fn median(&self) -> Option<f64> {
+        self.quantile(0.5)
+    }
+
+    /// Returns the key of the bucket of the (positive) value.
+    fn bucket_key(&self, value: f64) -> i32 {
+        (value.ln() / self.gamma_ln).ceil() as i32
+    }
+
+    /// Returns the value that represents the bucket of the key.
+    fn bucket_value(&self, key: i32) -> f64 {
+        let gamma = self.gamma_ln.exp();
+        2.0 * (self.gamma_ln * key as f64).exp() / (gamma + 1.0)
+    }
+}
+
+impl StreamingStatistic for QuantileSketch {
+    fn push(&mut self, value: f64) {
+        if value.is_nan() {
+            return;
+        }
+
+        if value.abs() < f64::MIN_POSITIVE {
+            self.zero_count += 1;
+        } else if value > 0.0 {
+            *self.positive.entry(self.bucket_key(value)).or_default() += 1;
+        } else {
+            *self.negative.entry(self.bucket_key(-value)).or_default() += 1;
+        }
+
+        self.count += 1;
+    }
+
+    /// # Panics
+    ///
+    /// Panics if the sketches do not have the same relative accuracy.
+    fn merge(&mut self, other: &Self) {
+        assert_eq!(
+            self.relative_accuracy, other.relative_accuracy,
+            "Only sketches with the same relative accuracy can be merged."
+        );
+
+        for (key, count) in &other.positive {
+            *self.positive.entry(*key).or_default() += count;
+        }
+
+        for (key, count) in &other.negative {
+            *self.negative.entry(*key).or_default() += count;
+        }
+
+        self.zero_count += other.zero_count;
+        self.count += other.count;
+    }
+}
+
+impl Histogram {
+    /// Creates an empty histogram of `bins` bins of equal width from `start` to `end`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if there are no bins or if `start` is not less than `end`.
+    pub fn new(start: f64, end: f64, bins: usize) -> Self {
+        assert!(bins > 0, "A histogram requires at least one bin.");
+        assert!(
+            start < end,
+            "The start of a histogram must be less than its end."
+        );
+
+        Self {
+            start,
+            end,
+            counts: vec![0; bins],
+            underflow: 0,
+            overflow: 0,
+        }
+    }
+
+    /// Returns the number of values in each bin.
+    pub fn counts(&self) -> &[u64] {
+        &self.counts
+    }
+
+    /// Returns the range of values of the bin.
+    pub fn bin_range(&self, bin: usize) -> std::ops::Range<f64> {
+        let width = self.bin_width();
+        self.start + bin as f64 * width..self.start + (bin + 1) as f64 * width
+    }
+
+    /// Returns the number of values less than the start of the histogram.
+    pub fn underflow(&self) -> u64 {
+        self.underflow
+    }
+
+    /// Returns the number of values greater than or equal to the end of the histogram.
+    pub fn overflow(&self) -> u64 {
+        self.overflow
+    }
+
+    /// Returns the width of the bins.
+    fn bin_width(&self) -> f64 {
+        (self.end - self.start) / self.counts.len() as f64
+    }
+}
+
+impl StreamingStatistic for Histogram {
+    fn push(&mut self, value: f64) {
+        if value.is_nan() {
+            return;
+        }
+
+        if value < self.start {
+            self.underflow += 1;
+        } else if value >= self.end {
+            self.overflow += 1;
+        } else {
+            let bin = ((value - self.start) / self.bin_width()) as usize;
+            let last_bin = self.counts.len() - 1;
+            self.counts[bin.min(last_bin)] += 1;
+        }
+    }
+
+    /// # Panics
+    ///
+    /// Panics if the histograms do not have the same bins.
+    fn merge(&mut self, other: &Self) {
+        assert!(
+            self.start == other.start
+                && self.end == other.end
+                && self.counts.len() == other.counts.len(),
+            "Only histograms with the same bins can be merged."
+        );
+
+        for (count, other_count) in self.counts.iter_mut().zip(&other.counts) {
+            *count += other_count;
+        }
+
+        self.underflow += other.underflow;
+        self.overflow += other.overflow;
+    }
+}
+
+/// Implements the statistic for tuples of statistics,
+/// so several statistics can be computed in the same pass.
+macro_rules! impl_streaming_statistic_for_tuple {
+    ($(($($name:ident $index:tt),+)),+) => {
+        $(
+            impl<$($name: StreamingStatistic),+> StreamingStatistic for ($($name,)+) {
+                fn push(&mut self, value: f64) {
+                    $(self.$index.push(value);)+
+                }
+
+                fn merge(&mut self, other: &Self) {
+                    $(self.$index.merge(&other.$index);)+
+                }
+            }
+        )+
+    };
+}
+
+impl_streaming_statistic_for_tuple!((A 0, B 1), (A 0, B 1, C 2), (A 0, B 1, C 2, D 3));
+
+impl<'a, T: Number, I> AliveElementsPart<'a, T, I> {
+    /// Adds the alive elements to the statistic in one pass and returns it.
+    pub fn statistics<S: StreamingStatistic>(&self, mut statistic: S) -> S {
+        for position in 0..self.parent_sequence.alive_elements_len() {
+            if let Some(element) = self.parent_sequence.alive_element_at(position) {
+                statistic.push(element.to_f64());
+            }
+        }
+
+        statistic
+    }
+
+    /// Returns the sum of the alive elements in their own type,
+    /// or None if the sum is not representable.
+    pub fn exact_sum(&self) -> Option<T> {
+        let mut sum = T::zero();
+
+        for position in 0..self.parent_sequence.alive_elements_len() {
+            if let Some(element) = self.parent_sequence.alive_element_at(position) {
+                sum = sum.checked_add(element)?;
+            }
+        }
+
+        Some(sum)
+    }
+}
+
+impl<'a, T: Number, I> RangePartImmut<'a, T, I> {
+    /// Adds the elements of the range to the statistic in one pass and returns it,
+    /// the elements that are not alive are skipped.
+    pub fn statistics<S: StreamingStatistic>(&self, mut statistic: S) -> S {
+        for index in 0..self.part.end() - self.part.start() {
+            if let Some(element) = self.nth_element(index) {
+                statistic.push(element.to_f64());
+            }
+        }
+
+        statistic
+    }
+
+    /// Returns the sum of the elements of the range in their own type,
+    /// or None if the sum is not representable. The elements that are not alive are skipped.
+    pub fn exact_sum(&self) -> Option<T> {
+        let mut sum = T::zero();
+
+        for index in 0..self.part.end() - self.part.start() {
+            if let Some(element) = self.nth_element(index) {
+                sum = sum.checked_add(element)?;
+            }
+        }
+
+        Some(sum)
+    }
+}
+
+impl<'a, T: Number, I> RangePartMut<'a, T, I> {
+    /// Adds the elements of the range to the statistic in one pass and returns it,
+    /// the elements are generated as they are needed.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the generation fails or if an element was dropped from storage.
+    pub fn statistics<S: StreamingStatistic>(&mut self, mut statistic: S) -> S {
+        for index in 0..self.part.end() - self.part.start() {
+            if let Some(element) = self.nth_element(index) {
+                statistic.push(element.to_f64());
+            }
+        }
+
+        statistic
+    }
+
+    /// Returns the sum of the elements of the range in their own type,
+    /// or None if the sum is not representable. The elements are generated as they are needed.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the generation fails or if an element was dropped from storage.
+    pub fn exact_sum(&mut self) -> Option<T> {
+        let mut sum = T::zero();
+
+        for index in 0..self.part.end() - self.part.start() {
+            if let Some(element) = self.nth_element(index) {
+                sum = sum.checked_add(element)?;
+            }
+        }
+
+        Some(sum)
+    }
+}